@@ -1,14 +1,15 @@
 import { Program, BN } from "@coral-xyz/anchor";
 import {
+  AccountMeta,
   PublicKey,
   TransactionInstruction,
   SystemProgram,
 } from "@solana/web3.js";
-import { ASSOCIATED_TOKEN_PROGRAM_ID, getAssociatedTokenAddress, TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import { ASSOCIATED_TOKEN_PROGRAM_ID, getAssociatedTokenAddress, TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID } from "@solana/spl-token";
 
 import { GenomeSolana } from "../target/types/genome_solana";
 
-import { BLOOM, CONSENSUS, FINISH, GENOME_OMNI_CONFIG, GENOME_SINGLE_CONFIG, getGenomePda, getProgram, getSingleConfig, getTournament, PLATFORM, Role, ROLE, roleToSeed, TEAM, TOKEN, TOURNAMENT } from "./utils";
+import { BADGE, BLOOM, BONUS, BRACKET, CONSENSUS, FINISH, LEAGUE, MatchResult, METADATA, SPONSORSHIP, TournamentMetadata, GENOME_OMNI_CONFIG, GENOME_SINGLE_CONFIG, getBonusPool, getGenomePda, getProgram, getSingleConfig, getTournament, PLATFORM, Role, ROLE, roleToSeed, STAKE, TEAM, TOKEN, TOURNAMENT, TROPHY } from "./utils";
 
 export class IxBuilder {
   public program: Program<GenomeSolana>;
@@ -95,7 +96,8 @@ export class IxBuilder {
     organizer: PublicKey,
     sponsor: PublicKey,
     assetMint: PublicKey,
-    params: any
+    params: any,
+    metadata: TournamentMetadata
   ): Promise<TransactionInstruction> {
     const configPda = await getGenomePda([this.singleConfigSeed]);
     const configData = await this.program.account.genomeSingleConfig.fetch(configPda);
@@ -108,13 +110,14 @@ export class IxBuilder {
     const rolePda = await getGenomePda([this.roleSeed, organizer.toBuffer()]);
     const verifierListPda = await getGenomePda([this.roleSeed, roleToSeed({ verifier: {} })]);
     const tokenPda = await getGenomePda([this.tokenSeed, assetMint.toBuffer()]);
-    const rewardPoolAta = await getAssociatedTokenAddress(assetMint, tournamentPda, true);
-    const sponsorAta = await getAssociatedTokenAddress(assetMint, sponsor, true);
+    const assetTokenProgram = await this.assetTokenProgram(assetMint);
+    const rewardPoolAta = params.nativeSol ? null : await getAssociatedTokenAddress(assetMint, tournamentPda, true, assetTokenProgram);
+    const sponsorAta = params.nativeSol ? null : await getAssociatedTokenAddress(assetMint, sponsor, true, assetTokenProgram);
     const platformAta = await getAssociatedTokenAddress(configData.nomeMint, configData.platformWallet, true);
     const organizerAta = await getAssociatedTokenAddress(configData.nomeMint, organizer, true);
 
     return this.program.methods
-      .createTournament(params)
+      .createTournament(params, metadata)
       .accountsStrict({
         organizer,
         sponsor,
@@ -124,6 +127,8 @@ export class IxBuilder {
         tournament: tournamentPda,
         consensus: consensusPda,
         finishMetaData: finishMetaDataPda,
+        metadata: await getGenomePda([METADATA, idBuffer]),
+        sponsorship: await getGenomePda([SPONSORSHIP, idBuffer, sponsor.toBuffer()]),
         assetMint,
         nomeMint: configData.nomeMint,
         tokenInfo: tokenPda,
@@ -134,7 +139,8 @@ export class IxBuilder {
         bloomFilter: bloomPda,
         associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
-        tokenProgram: TOKEN_PROGRAM_ID,
+        tokenProgram: assetTokenProgram,
+        nomeTokenProgram: TOKEN_PROGRAM_ID,
       })
       .instruction();
   }
@@ -169,6 +175,7 @@ export class IxBuilder {
         user,
         roleInfo: await getGenomePda([this.roleSeed, user.toBuffer()]),
         roleList: await getGenomePda([this.roleSeed, roleToSeed(role)]),
+        stake: await getGenomePda([STAKE, user.toBuffer()]),
         config: await getGenomePda([this.singleConfigSeed]),
       })
       .instruction();
@@ -207,6 +214,46 @@ export class IxBuilder {
       .instruction();
   }
 
+  async updateTournamentIx(
+    organizer: PublicKey,
+    tournamentId: number,
+    params: any
+  ): Promise<TransactionInstruction> {
+    const idBuffer = Buffer.alloc(4);
+    idBuffer.writeUInt32LE(tournamentId, 0);
+    const tournament = await getTournament(tournamentId);
+    return this.program.methods
+      .updateTournament(tournamentId, params)
+      .accountsStrict({
+        organizer,
+        config: await getGenomePda([this.singleConfigSeed]),
+        verifierList: await getGenomePda([this.roleSeed, roleToSeed({ verifier: {} })]),
+        tokenInfo: await getGenomePda([this.tokenSeed, tournament.config.assetMint.toBuffer()]),
+        tournament: await getGenomePda([this.tournamentSeed, idBuffer]),
+        bloomFilter: await getGenomePda([this.bloomSeed, idBuffer]),
+        finishMetaData: await getGenomePda([this.finishSeed, idBuffer]),
+        systemProgram: SystemProgram.programId,
+      })
+      .instruction();
+  }
+
+  async updateTournamentMetadataIx(
+    organizer: PublicKey,
+    tournamentId: number,
+    metadata: TournamentMetadata
+  ): Promise<TransactionInstruction> {
+    const idBuffer = Buffer.alloc(4);
+    idBuffer.writeUInt32LE(tournamentId, 0);
+    return this.program.methods
+      .updateTournamentMetadata(tournamentId, metadata)
+      .accountsStrict({
+        organizer,
+        tournament: await getGenomePda([this.tournamentSeed, idBuffer]),
+        metadata: await getGenomePda([METADATA, idBuffer]),
+      })
+      .instruction();
+  }
+
   async registerTournamentIx(
     registerParams: any,
   ): Promise<TransactionInstruction> {
@@ -218,8 +265,8 @@ export class IxBuilder {
     const teamPda = await getGenomePda([this.teamSeed, idBuffer, registerParams.captain.toBuffer()]);
     const bloomPda = await getGenomePda([this.bloomSeed, idBuffer]);
 
-    const participantAta = await getAssociatedTokenAddress(tournament.config.assetMint, registerParams.participant, true);
-    const rewardPoolAta = await getAssociatedTokenAddress(tournament.config.assetMint, tournamentPda, true);
+    const participantAta = await this.assetAta(tournament, registerParams.participant);
+    const rewardPoolAta = await this.assetAta(tournament, tournamentPda);
 
     return this.program.methods
       .registerTournament(registerParams)
@@ -233,11 +280,260 @@ export class IxBuilder {
         rewardPoolAta,
         bloomFilter: bloomPda,
         systemProgram: SystemProgram.programId,
+        tokenProgram: await this.assetTokenProgram(tournament.config.assetMint),
+      })
+      .instruction();
+  }
+
+  async addSponsorshipIx(
+    sponsor: PublicKey,
+    tournamentId: number,
+    amount: BN
+  ): Promise<TransactionInstruction> {
+    const idBuffer = Buffer.alloc(4);
+    idBuffer.writeUInt32LE(tournamentId, 0);
+    const tournamentPda = await getGenomePda([this.tournamentSeed, idBuffer]);
+    const tournament = await getTournament(tournamentId);
+    const assetMint = tournament.config.assetMint;
+    return this.program.methods
+      .addSponsorship(tournamentId, amount)
+      .accountsStrict({
+        sponsor,
+        tournament: tournamentPda,
+        sponsorship: await getGenomePda([SPONSORSHIP, idBuffer, sponsor.toBuffer()]),
+        assetMint,
+        sponsorAta: await this.assetAta(tournament, sponsor),
+        rewardPoolAta: await this.assetAta(tournament, tournamentPda),
+        systemProgram: SystemProgram.programId,
+        tokenProgram: await this.assetTokenProgram(tournament.config.assetMint),
+      })
+      .instruction();
+  }
+
+  async addBonusPoolIx(
+    sponsor: PublicKey,
+    tournamentId: number,
+    bonusMint: PublicKey,
+    amount: BN
+  ): Promise<TransactionInstruction> {
+    const idBuffer = Buffer.alloc(4);
+    idBuffer.writeUInt32LE(tournamentId, 0);
+    const tournamentPda = await getGenomePda([this.tournamentSeed, idBuffer]);
+    const tournament = await getTournament(tournamentId);
+    return this.program.methods
+      .addBonusPool(tournamentId, amount)
+      .accountsStrict({
+        sponsor,
+        tournament: tournamentPda,
+        bonusPool: await getGenomePda([BONUS, idBuffer, bonusMint.toBuffer()]),
+        bonusMint,
+        assetMint: tournament.config.assetMint,
+        tokenInfo: await getGenomePda([this.tokenSeed, bonusMint.toBuffer()]),
+        sponsorAta: await getAssociatedTokenAddress(bonusMint, sponsor, true),
+        bonusPoolAta: await getAssociatedTokenAddress(bonusMint, tournamentPda, true),
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .instruction();
+  }
+
+  async closeBonusPoolIx(
+    caller: PublicKey,
+    tournamentId: number,
+    bonusMint: PublicKey
+  ): Promise<TransactionInstruction> {
+    const idBuffer = Buffer.alloc(4);
+    idBuffer.writeUInt32LE(tournamentId, 0);
+    const tournamentPda = await getGenomePda([this.tournamentSeed, idBuffer]);
+    const bonusPool = await getBonusPool(tournamentId, bonusMint);
+    return this.program.methods
+      .closeBonusPool(tournamentId)
+      .accountsStrict({
+        caller,
+        sponsor: bonusPool.sponsor,
+        tournament: tournamentPda,
+        bonusPool: await getGenomePda([BONUS, idBuffer, bonusMint.toBuffer()]),
+        bonusMint,
+        sponsorAta: await getAssociatedTokenAddress(bonusMint, bonusPool.sponsor, true),
+        bonusPoolAta: await getAssociatedTokenAddress(bonusMint, tournamentPda, true),
         tokenProgram: TOKEN_PROGRAM_ID,
       })
       .instruction();
   }
 
+  // Bonus pool, its mint and its token account for every bonus mint of the tournament.
+  private async bonusPoolAccounts(tournamentId: number, bonusMints: PublicKey[]): Promise<AccountMeta[]> {
+    const idBuffer = Buffer.alloc(4);
+    idBuffer.writeUInt32LE(tournamentId, 0);
+    const tournamentPda = await getGenomePda([this.tournamentSeed, idBuffer]);
+    const accounts: AccountMeta[] = [];
+    for (const mint of bonusMints) {
+      accounts.push(
+        { pubkey: await getGenomePda([BONUS, idBuffer, mint.toBuffer()]), isSigner: false, isWritable: false },
+        { pubkey: mint, isSigner: false, isWritable: false },
+        { pubkey: await getAssociatedTokenAddress(mint, tournamentPda, true), isSigner: false, isWritable: true },
+      );
+    }
+    return accounts;
+  }
+
+  private async bonusRecipientAccounts(participant: PublicKey, bonusMints: PublicKey[]): Promise<AccountMeta[]> {
+    return Promise.all(
+      bonusMints.map(async (mint) => ({
+        pubkey: await getAssociatedTokenAddress(mint, participant, true),
+        isSigner: false,
+        isWritable: true,
+      }))
+    );
+  }
+
+  // Native SOL tournaments hold the pool on the tournament account and pay wallets directly
+  private async assetAta(tournament: any, owner: PublicKey): Promise<PublicKey | null> {
+    const assetMint = tournament.config.assetMint;
+    return tournament.config.nativeSol
+      ? null
+      : await getAssociatedTokenAddress(assetMint, owner, true, await this.assetTokenProgram(assetMint));
+  }
+
+  // Token-2022 assets are held in accounts of the program that owns the mint
+  private async assetTokenProgram(assetMint: PublicKey): Promise<PublicKey> {
+    const mintInfo = await this.program.provider.connection.getAccountInfo(assetMint);
+    return mintInfo?.owner ?? TOKEN_PROGRAM_ID;
+  }
+
+  async setAllowlistIx(
+    organizer: PublicKey,
+    tournamentId: number,
+    allowlistRoot: number[] | null
+  ): Promise<TransactionInstruction> {
+    const idBuffer = Buffer.alloc(4);
+    idBuffer.writeUInt32LE(tournamentId, 0);
+    return this.program.methods
+      .setAllowlist(tournamentId, allowlistRoot)
+      .accountsStrict({
+        organizer,
+        tournament: await getGenomePda([this.tournamentSeed, idBuffer]),
+      })
+      .instruction();
+  }
+
+  async acceptInviteIx(
+    participant: PublicKey,
+    tournamentId: number,
+    captain: PublicKey
+  ): Promise<TransactionInstruction> {
+    const idBuffer = Buffer.alloc(4);
+    idBuffer.writeUInt32LE(tournamentId, 0);
+    const tournamentPda = await getGenomePda([this.tournamentSeed, idBuffer]);
+    const tournament = await getTournament(tournamentId);
+    const assetMint = tournament.config.assetMint;
+    return this.program.methods
+      .acceptInvite(tournamentId, captain)
+      .accountsStrict({
+        participant,
+        tournament: tournamentPda,
+        team: await getGenomePda([this.teamSeed, idBuffer, captain.toBuffer()]),
+        mint: assetMint,
+        participantAta: await this.assetAta(tournament, participant),
+        rewardPoolAta: await this.assetAta(tournament, tournamentPda),
+        bloomFilter: await getGenomePda([this.bloomSeed, idBuffer]),
+        systemProgram: SystemProgram.programId,
+        tokenProgram: await this.assetTokenProgram(tournament.config.assetMint),
+      })
+      .instruction();
+  }
+
+  async expireInvitesIx(
+    caller: PublicKey,
+    tournamentId: number,
+    captain: PublicKey
+  ): Promise<TransactionInstruction> {
+    const idBuffer = Buffer.alloc(4);
+    idBuffer.writeUInt32LE(tournamentId, 0);
+    const tournamentPda = await getGenomePda([this.tournamentSeed, idBuffer]);
+    const tournament = await getTournament(tournamentId);
+    const assetMint = tournament.config.assetMint;
+    return this.program.methods
+      .expireInvites(tournamentId, captain)
+      .accountsStrict({
+        caller,
+        tournament: tournamentPda,
+        team: await getGenomePda([this.teamSeed, idBuffer, captain.toBuffer()]),
+        mint: assetMint,
+        captainAta: await this.assetAta(tournament, captain),
+        captainWallet: captain,
+        rewardPoolAta: await this.assetAta(tournament, tournamentPda),
+        tokenProgram: await this.assetTokenProgram(tournament.config.assetMint),
+      })
+      .instruction();
+  }
+
+  async leaveTeamIx(
+    participant: PublicKey,
+    tournamentId: number,
+    captain: PublicKey
+  ): Promise<TransactionInstruction> {
+    const idBuffer = Buffer.alloc(4);
+    idBuffer.writeUInt32LE(tournamentId, 0);
+    const tournamentPda = await getGenomePda([this.tournamentSeed, idBuffer]);
+    const tournament = await getTournament(tournamentId);
+    const assetMint = tournament.config.assetMint;
+    return this.program.methods
+      .leaveTeam(tournamentId, captain)
+      .accountsStrict({
+        participant,
+        tournament: tournamentPda,
+        team: await getGenomePda([this.teamSeed, idBuffer, captain.toBuffer()]),
+        mint: assetMint,
+        participantAta: await this.assetAta(tournament, participant),
+        captainAta: await this.assetAta(tournament, captain),
+        captainWallet: captain,
+        rewardPoolAta: await this.assetAta(tournament, tournamentPda),
+        bloomFilter: await getGenomePda([this.bloomSeed, idBuffer]),
+        systemProgram: SystemProgram.programId,
+        tokenProgram: await this.assetTokenProgram(tournament.config.assetMint),
+      })
+      .instruction();
+  }
+
+  async disbandTeamIx(
+    captain: PublicKey,
+    tournamentId: number
+  ): Promise<TransactionInstruction> {
+    const idBuffer = Buffer.alloc(4);
+    idBuffer.writeUInt32LE(tournamentId, 0);
+    const tournamentPda = await getGenomePda([this.tournamentSeed, idBuffer]);
+    const tournament = await getTournament(tournamentId);
+    const assetMint = tournament.config.assetMint;
+    const teamPda = await getGenomePda([this.teamSeed, idBuffer, captain.toBuffer()]);
+    const team = await this.program.account.team.fetch(teamPda);
+    const memberAtas = await Promise.all(
+      team.participants
+        .filter((participant) => participant.accepted && !participant.paidByCaptain)
+        .map(async (participant) => ({
+          pubkey: (await this.assetAta(tournament, participant.pubkey)) ?? participant.pubkey,
+          isSigner: false,
+          isWritable: true,
+        }))
+    );
+    return this.program.methods
+      .disbandTeam(tournamentId)
+      .accountsStrict({
+        captain,
+        tournament: tournamentPda,
+        team: teamPda,
+        mint: assetMint,
+        captainAta: await this.assetAta(tournament, captain),
+        rewardPoolAta: await this.assetAta(tournament, tournamentPda),
+        bloomFilter: await getGenomePda([this.bloomSeed, idBuffer]),
+        systemProgram: SystemProgram.programId,
+        tokenProgram: await this.assetTokenProgram(tournament.config.assetMint),
+      })
+      .remainingAccounts(memberAtas)
+      .instruction();
+  }
+
   async setBloomPrecisionIx(
     admin: PublicKey,
     newPrecision: BN
@@ -251,6 +547,45 @@ export class IxBuilder {
       .instruction();
   }
 
+  async setCancelPenaltyIx(
+    admin: PublicKey,
+    penalty: BN
+  ): Promise<TransactionInstruction> {
+    return this.program.methods
+      .setCancelPenalty(penalty)
+      .accountsStrict({
+        admin,
+        config: await getGenomePda([this.singleConfigSeed]),
+      })
+      .instruction();
+  }
+
+  async setClaimPeriodIx(
+    admin: PublicKey,
+    claimPeriod: BN
+  ): Promise<TransactionInstruction> {
+    return this.program.methods
+      .setClaimPeriod(claimPeriod)
+      .accountsStrict({
+        admin,
+        config: await getGenomePda([this.singleConfigSeed]),
+      })
+      .instruction();
+  }
+
+  async setSlashRateIx(
+    admin: PublicKey,
+    slashRate: BN
+  ): Promise<TransactionInstruction> {
+    return this.program.methods
+      .setSlashRate(slashRate)
+      .accountsStrict({
+        admin,
+        config: await getGenomePda([this.singleConfigSeed]),
+      })
+      .instruction();
+  }
+
   async startTournamentIx(
     verifier: PublicKey,
     tournamentId: number,
@@ -262,11 +597,13 @@ export class IxBuilder {
     const consensusPda = await getGenomePda([this.consensusSeed, idBuffer]);
     const rolePda = await getGenomePda([this.roleSeed, verifier.toBuffer()]);
     const verifierListPda = await getGenomePda([this.roleSeed, roleToSeed({ verifier: {} })]);
+    const stakePda = await getGenomePda([STAKE, verifier.toBuffer()]);
     return this.program.methods
       .startTournament(tournamentId)
       .accountsStrict({
         verifier,
         roleInfo: rolePda,
+        stake: stakePda,
         verifierList: verifierListPda,
         config: configPda,
         consensus: consensusPda,
@@ -288,11 +625,13 @@ export class IxBuilder {
     const tournament = await getTournament(tournamentId);
     const roleOrgPda = await getGenomePda([this.roleSeed, tournament.organizer.toBuffer()]);
     const verifierListPda = await getGenomePda([this.roleSeed, roleToSeed({ verifier: {} })]);
+    const stakePda = await getGenomePda([STAKE, verifier.toBuffer()]);
     return this.program.methods
       .cancelTournament(tournamentId)
       .accountsStrict({
         verifier,
         roleInfoVer: roleVerPda,
+        stake: stakePda,
         verifierList: verifierListPda,
         config: configPda,
         consensus: consensusPda,
@@ -302,10 +641,151 @@ export class IxBuilder {
       .instruction();
   }
 
-  async finishTournamentIx(
+  async organizerCancelIx(
+    organizer: PublicKey,
+    tournamentId: number,
+  ): Promise<TransactionInstruction> {
+    const idBuffer = Buffer.alloc(4);
+    idBuffer.writeUInt32LE(tournamentId, 0);
+    const tournamentPda = await getGenomePda([this.tournamentSeed, idBuffer]);
+    const tournament = await getTournament(tournamentId);
+    const assetMint = tournament.config.assetMint;
+    return this.program.methods
+      .organizerCancel(tournamentId)
+      .accountsStrict({
+        organizer,
+        sponsor: tournament.config.sponsor,
+        config: await getGenomePda([this.singleConfigSeed]),
+        tournament: tournamentPda,
+        roleInfoOrg: await getGenomePda([this.roleSeed, tournament.organizer.toBuffer()]),
+        sponsorship: await getGenomePda([SPONSORSHIP, idBuffer, tournament.config.sponsor.toBuffer()]),
+        assetMint,
+        sponsorAta: await this.assetAta(tournament, tournament.config.sponsor),
+        rewardPoolAta: await this.assetAta(tournament, tournamentPda),
+        tokenProgram: await this.assetTokenProgram(tournament.config.assetMint),
+      })
+      .instruction();
+  }
+
+  async expireTournamentIx(
+    caller: PublicKey,
+    tournamentId: number,
+  ): Promise<TransactionInstruction> {
+    const idBuffer = Buffer.alloc(4);
+    idBuffer.writeUInt32LE(tournamentId, 0);
+    const configPda = await getGenomePda([this.singleConfigSeed]);
+    const tournamentPda = await getGenomePda([this.tournamentSeed, idBuffer]);
+    const tournament = await getTournament(tournamentId);
+    const roleOrgPda = await getGenomePda([this.roleSeed, tournament.organizer.toBuffer()]);
+    return this.program.methods
+      .expireTournament(tournamentId)
+      .accountsStrict({
+        caller,
+        config: configPda,
+        tournament: tournamentPda,
+        roleInfoOrg: roleOrgPda,
+      })
+      .instruction();
+  }
+
+  async createBracketIx(
+    payer: PublicKey,
+    tournamentId: number,
+    captains: PublicKey[]
+  ): Promise<TransactionInstruction> {
+    const idBuffer = Buffer.alloc(4);
+    idBuffer.writeUInt32LE(tournamentId, 0);
+    const teams = await Promise.all(
+      captains.map(async (captain) => ({
+        pubkey: await getGenomePda([this.teamSeed, idBuffer, captain.toBuffer()]),
+        isSigner: false,
+        isWritable: false,
+      }))
+    );
+    return this.program.methods
+      .createBracket(tournamentId)
+      .accountsStrict({
+        payer,
+        tournament: await getGenomePda([this.tournamentSeed, idBuffer]),
+        bracket: await getGenomePda([BRACKET, idBuffer]),
+        systemProgram: SystemProgram.programId,
+      })
+      .remainingAccounts(teams)
+      .instruction();
+  }
+
+  async reportMatchIx(
     verifier: PublicKey,
     tournamentId: number,
+    matchIndex: number,
     winner: PublicKey
+  ): Promise<TransactionInstruction> {
+    const idBuffer = Buffer.alloc(4);
+    idBuffer.writeUInt32LE(tournamentId, 0);
+    return this.program.methods
+      .reportMatch(tournamentId, matchIndex, winner)
+      .accountsStrict({
+        verifier,
+        roleInfo: await getGenomePda([this.roleSeed, verifier.toBuffer()]),
+        verifierList: await getGenomePda([this.roleSeed, roleToSeed({ verifier: {} })]),
+        config: await getGenomePda([this.singleConfigSeed]),
+        tournament: await getGenomePda([this.tournamentSeed, idBuffer]),
+        bracket: await getGenomePda([BRACKET, idBuffer]),
+      })
+      .instruction();
+  }
+
+  async createLeagueIx(
+    payer: PublicKey,
+    tournamentId: number,
+    captains: PublicKey[]
+  ): Promise<TransactionInstruction> {
+    const idBuffer = Buffer.alloc(4);
+    idBuffer.writeUInt32LE(tournamentId, 0);
+    const teams = await Promise.all(
+      captains.map(async (captain) => ({
+        pubkey: await getGenomePda([this.teamSeed, idBuffer, captain.toBuffer()]),
+        isSigner: false,
+        isWritable: false,
+      }))
+    );
+    return this.program.methods
+      .createLeague(tournamentId)
+      .accountsStrict({
+        payer,
+        tournament: await getGenomePda([this.tournamentSeed, idBuffer]),
+        league: await getGenomePda([LEAGUE, idBuffer]),
+        systemProgram: SystemProgram.programId,
+      })
+      .remainingAccounts(teams)
+      .instruction();
+  }
+
+  async reportLeagueMatchIx(
+    verifier: PublicKey,
+    tournamentId: number,
+    matchIndex: number,
+    result: MatchResult
+  ): Promise<TransactionInstruction> {
+    const idBuffer = Buffer.alloc(4);
+    idBuffer.writeUInt32LE(tournamentId, 0);
+    return this.program.methods
+      .reportLeagueMatch(tournamentId, matchIndex, result)
+      .accountsStrict({
+        verifier,
+        roleInfo: await getGenomePda([this.roleSeed, verifier.toBuffer()]),
+        verifierList: await getGenomePda([this.roleSeed, roleToSeed({ verifier: {} })]),
+        config: await getGenomePda([this.singleConfigSeed]),
+        tournament: await getGenomePda([this.tournamentSeed, idBuffer]),
+        league: await getGenomePda([LEAGUE, idBuffer]),
+      })
+      .instruction();
+  }
+
+  async finishTournamentIx(
+    verifier: PublicKey,
+    tournamentId: number,
+    placements: PublicKey[]
   ): Promise<TransactionInstruction> {
     const idBuffer = Buffer.alloc(4);
     idBuffer.writeUInt32LE(tournamentId, 0);
@@ -314,26 +794,217 @@ export class IxBuilder {
     const tournamentPda = await getGenomePda([this.tournamentSeed, idBuffer]);
     const tournament = await getTournament(tournamentId);
     const assetMint = tournament.config.assetMint;
-    const rewardPoolAta = await getAssociatedTokenAddress(tournament.config.assetMint, tournamentPda, true);
-    const organizerAta = await getAssociatedTokenAddress(tournament.config.assetMint, tournament.organizer, true);
+    const rewardPoolAta = await this.assetAta(tournament, tournamentPda);
+    const organizerAta = await this.assetAta(tournament, tournament.organizer);
     const rolePda = await getGenomePda([this.roleSeed, verifier.toBuffer()]);
     const verifierListPda = await getGenomePda([this.roleSeed, roleToSeed({ verifier: {} })]);
     const finishMetaDataPda = await getGenomePda([this.finishSeed, idBuffer]);
+    const bracketPda = "singleElimination" in tournament.config.format
+      ? await getGenomePda([BRACKET, idBuffer])
+      : null;
+    const leaguePda = "league" in tournament.config.format
+      ? await getGenomePda([LEAGUE, idBuffer])
+      : null;
+    const stakePda = await getGenomePda([STAKE, verifier.toBuffer()]);
     return this.program.methods
-      .finishTournament(tournamentId, winner)
+      .finishTournament(tournamentId, placements)
       .accountsStrict({
         verifier,
         organizer: tournament.organizer,
         roleInfo: rolePda,
+        stake: stakePda,
         verifierList: verifierListPda,
         config: configPda,
         consensus: consensusPda,
         tournament: tournamentPda,
         assetMint,
         finishMetaData: finishMetaDataPda,
+        bracket: bracketPda,
+        league: leaguePda,
         organizerAta,
         rewardPoolAta,
-        tokenProgram: TOKEN_PROGRAM_ID,
+        tokenProgram: await this.assetTokenProgram(tournament.config.assetMint),
+      })
+      .instruction();
+  }
+
+  async distributeRewardsIx(
+    caller: PublicKey,
+    tournamentId: number,
+    captain: PublicKey
+  ): Promise<TransactionInstruction> {
+    const idBuffer = Buffer.alloc(4);
+    idBuffer.writeUInt32LE(tournamentId, 0);
+    const tournamentPda = await getGenomePda([this.tournamentSeed, idBuffer]);
+    const teamPda = await getGenomePda([this.teamSeed, idBuffer, captain.toBuffer()]);
+    const tournament = await getTournament(tournamentId);
+    const assetMint = tournament.config.assetMint;
+    const team = await this.program.account.team.fetch(teamPda);
+    const trophyMint = await getGenomePda([TROPHY, idBuffer, captain.toBuffer()]);
+    const remainingAccounts = await this.bonusPoolAccounts(tournamentId, tournament.bonusMints);
+    for (const p of team.participants.filter((p) => p.accepted && !p.claimed)) {
+      remainingAccounts.push(
+        { pubkey: (await this.assetAta(tournament, p.pubkey)) ?? p.pubkey, isSigner: false, isWritable: true },
+        { pubkey: p.pubkey, isSigner: false, isWritable: false },
+        {
+          pubkey: await getAssociatedTokenAddress(trophyMint, p.pubkey, true, TOKEN_2022_PROGRAM_ID),
+          isSigner: false,
+          isWritable: true,
+        },
+        ...(await this.bonusRecipientAccounts(p.pubkey, tournament.bonusMints)),
+      );
+    }
+    return this.program.methods
+      .distributeRewards(tournamentId, captain)
+      .accountsStrict({
+        caller,
+        tournament: tournamentPda,
+        team: teamPda,
+        assetMint,
+        finishMetaData: await getGenomePda([this.finishSeed, idBuffer]),
+        rewardPoolAta: await this.assetAta(tournament, tournamentPda),
+        trophyAuthority: await getGenomePda([TROPHY]),
+        trophyMint,
+        tokenProgram: await this.assetTokenProgram(tournament.config.assetMint),
+        token2022Program: TOKEN_2022_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .remainingAccounts(remainingAccounts)
+      .instruction();
+  }
+
+  async sweepUnclaimedIx(
+    caller: PublicKey,
+    tournamentId: number
+  ): Promise<TransactionInstruction> {
+    const idBuffer = Buffer.alloc(4);
+    idBuffer.writeUInt32LE(tournamentId, 0);
+    const tournamentPda = await getGenomePda([this.tournamentSeed, idBuffer]);
+    const platformWallet = await getGenomePda([this.platformSeed]);
+    const tournament = await getTournament(tournamentId);
+    const assetMint = tournament.config.assetMint;
+    return this.program.methods
+      .sweepUnclaimed(tournamentId)
+      .accountsStrict({
+        caller,
+        tournament: tournamentPda,
+        platformWallet,
+        assetMint,
+        platformAta: await this.assetAta(tournament, platformWallet),
+        rewardPoolAta: await this.assetAta(tournament, tournamentPda),
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        tokenProgram: await this.assetTokenProgram(tournament.config.assetMint),
+      })
+      .instruction();
+  }
+
+  async closeTeamIx(
+    tournamentId: number,
+    captain: PublicKey
+  ): Promise<TransactionInstruction> {
+    const idBuffer = Buffer.alloc(4);
+    idBuffer.writeUInt32LE(tournamentId, 0);
+    return this.program.methods
+      .closeTeam(tournamentId, captain)
+      .accountsStrict({
+        captain,
+        tournament: await getGenomePda([this.tournamentSeed, idBuffer]),
+        team: await getGenomePda([this.teamSeed, idBuffer, captain.toBuffer()]),
+        finishMetaData: await getGenomePda([this.finishSeed, idBuffer]),
+      })
+      .instruction();
+  }
+
+  async closeSponsorshipIx(
+    tournamentId: number,
+    sponsor: PublicKey
+  ): Promise<TransactionInstruction> {
+    const idBuffer = Buffer.alloc(4);
+    idBuffer.writeUInt32LE(tournamentId, 0);
+    return this.program.methods
+      .closeSponsorship(tournamentId, sponsor)
+      .accountsStrict({
+        sponsor,
+        tournament: await getGenomePda([this.tournamentSeed, idBuffer]),
+        sponsorship: await getGenomePda([SPONSORSHIP, idBuffer, sponsor.toBuffer()]),
+      })
+      .instruction();
+  }
+
+  async closeTournamentIx(
+    caller: PublicKey,
+    tournamentId: number
+  ): Promise<TransactionInstruction> {
+    const idBuffer = Buffer.alloc(4);
+    idBuffer.writeUInt32LE(tournamentId, 0);
+    const tournamentPda = await getGenomePda([this.tournamentSeed, idBuffer]);
+    const tournament = await getTournament(tournamentId);
+    const assetMint = tournament.config.assetMint;
+    const finished = "finished" in tournament.status;
+
+    let bracketPda = null;
+    let leaguePda = null;
+    let schedulePayer = null;
+    // An expired tournament may have created its schedule before it was canceled
+    if ("singleElimination" in tournament.config.format) {
+      const pda = await getGenomePda([BRACKET, idBuffer]);
+      const bracket = await this.program.account.bracket.fetchNullable(pda);
+      if (bracket) {
+        bracketPda = pda;
+        schedulePayer = bracket.payer;
+      }
+    } else if ("league" in tournament.config.format) {
+      const pda = await getGenomePda([LEAGUE, idBuffer]);
+      const league = await this.program.account.league.fetchNullable(pda);
+      if (league) {
+        leaguePda = pda;
+        schedulePayer = league.payer;
+      }
+    }
+
+    return this.program.methods
+      .closeTournament(tournamentId)
+      .accountsStrict({
+        caller,
+        organizer: tournament.organizer,
+        tournament: tournamentPda,
+        consensus: await getGenomePda([this.consensusSeed, idBuffer]),
+        finishMetaData: await getGenomePda([this.finishSeed, idBuffer]),
+        bloomFilter: await getGenomePda([this.bloomSeed, idBuffer]),
+        metadata: await getGenomePda([METADATA, idBuffer]),
+        bracket: bracketPda,
+        league: leaguePda,
+        schedulePayer,
+        assetMint,
+        organizerAta: finished ? await this.assetAta(tournament, tournament.organizer) : null,
+        rewardPoolAta: await this.assetAta(tournament, tournamentPda),
+        tokenProgram: await this.assetTokenProgram(tournament.config.assetMint),
+      })
+      .instruction();
+  }
+
+  async claimBadgeIx(
+    participant: PublicKey,
+    tournamentId: number,
+    captain: PublicKey
+  ): Promise<TransactionInstruction> {
+    const idBuffer = Buffer.alloc(4);
+    idBuffer.writeUInt32LE(tournamentId, 0);
+    const badgeMint = await getGenomePda([BADGE, idBuffer]);
+    return this.program.methods
+      .claimBadge(tournamentId, captain)
+      .accountsStrict({
+        participant,
+        tournament: await getGenomePda([this.tournamentSeed, idBuffer]),
+        team: await getGenomePda([this.teamSeed, idBuffer, captain.toBuffer()]),
+        badgeAuthority: await getGenomePda([BADGE]),
+        badgeMint,
+        participantBadgeAta: await getAssociatedTokenAddress(badgeMint, participant, true, TOKEN_2022_PROGRAM_ID),
+        token2022Program: TOKEN_2022_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
       })
       .instruction();
   }
@@ -350,8 +1021,13 @@ export class IxBuilder {
     const finishMetaDataPda = await getGenomePda([this.finishSeed, idBuffer]);
     const tournament = await getTournament(tournamentId);
     const assetMint = tournament.config.assetMint;
-    const participantAta = await getAssociatedTokenAddress(assetMint, participant, true);
-    const rewardPoolAta = await getAssociatedTokenAddress(assetMint, tournamentPda, true);
+    const participantAta = await this.assetAta(tournament, participant);
+    const rewardPoolAta = await this.assetAta(tournament, tournamentPda);
+    const trophyMint = await getGenomePda([TROPHY, idBuffer, captain.toBuffer()]);
+    const remainingAccounts = [
+      ...(await this.bonusPoolAccounts(tournamentId, tournament.bonusMints)),
+      ...(await this.bonusRecipientAccounts(participant, tournament.bonusMints)),
+    ];
     return this.program.methods
       .claimReward(tournamentId, captain)
       .accountsStrict({
@@ -362,8 +1038,15 @@ export class IxBuilder {
         assetMint,
         participantAta,
         rewardPoolAta,
-        tokenProgram: TOKEN_PROGRAM_ID,
+        trophyAuthority: await getGenomePda([TROPHY]),
+        trophyMint,
+        participantTrophyAta: await getAssociatedTokenAddress(trophyMint, participant, true, TOKEN_2022_PROGRAM_ID),
+        tokenProgram: await this.assetTokenProgram(tournament.config.assetMint),
+        token2022Program: TOKEN_2022_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
       })
+      .remainingAccounts(remainingAccounts)
       .instruction();
   }
 
@@ -378,8 +1061,8 @@ export class IxBuilder {
     const teamPda = await getGenomePda([this.teamSeed, idBuffer, captain.toBuffer()]);
     const tournament = await getTournament(tournamentId);
     const assetMint = tournament.config.assetMint;
-    const participantAta = await getAssociatedTokenAddress(assetMint, participant, true);
-    const rewardPoolAta = await getAssociatedTokenAddress(assetMint, tournamentPda, true);
+    const participantAta = await this.assetAta(tournament, participant);
+    const rewardPoolAta = await this.assetAta(tournament, tournamentPda);
     return this.program.methods
       .claimRefund(tournamentId, captain)
       .accountsStrict({
@@ -389,7 +1072,7 @@ export class IxBuilder {
         assetMint,
         participantAta,
         rewardPoolAta,
-        tokenProgram: TOKEN_PROGRAM_ID,
+        tokenProgram: await this.assetTokenProgram(tournament.config.assetMint),
       })
       .instruction();
   }
@@ -419,6 +1102,90 @@ export class IxBuilder {
       .instruction();
   }
 
+  async stakeIx(
+    verifier: PublicKey,
+    amount: BN
+  ): Promise<TransactionInstruction> {
+    const configPda = await getGenomePda([this.singleConfigSeed]);
+    const roleInfoPda = await getGenomePda([this.roleSeed, verifier.toBuffer()]);
+    const stakePda = await getGenomePda([STAKE, verifier.toBuffer()]);
+    const configData = await getSingleConfig();
+    const verifierAta = await getAssociatedTokenAddress(configData.nomeMint, verifier, true);
+    const stakeAta = await getAssociatedTokenAddress(configData.nomeMint, stakePda, true);
+    return this.program.methods
+      .stake(amount)
+      .accountsStrict({
+        verifier,
+        roleInfo: roleInfoPda,
+        config: configPda,
+        stake: stakePda,
+        nomeMint: configData.nomeMint,
+        verifierAta,
+        stakeAta,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .instruction();
+  }
+
+  async unstakeIx(
+    verifier: PublicKey,
+    amount: BN
+  ): Promise<TransactionInstruction> {
+    const configPda = await getGenomePda([this.singleConfigSeed]);
+    const stakePda = await getGenomePda([STAKE, verifier.toBuffer()]);
+    const configData = await getSingleConfig();
+    const verifierAta = await getAssociatedTokenAddress(configData.nomeMint, verifier, true);
+    const stakeAta = await getAssociatedTokenAddress(configData.nomeMint, stakePda, true);
+    return this.program.methods
+      .unstake(amount)
+      .accountsStrict({
+        verifier,
+        config: configPda,
+        stake: stakePda,
+        nomeMint: configData.nomeMint,
+        verifierAta,
+        stakeAta,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .instruction();
+  }
+
+  async settleVerifierIx(
+    caller: PublicKey,
+    tournamentId: number,
+    verifier: PublicKey
+  ): Promise<TransactionInstruction> {
+    const idBuffer = Buffer.alloc(4);
+    idBuffer.writeUInt32LE(tournamentId, 0);
+    const configPda = await getGenomePda([this.singleConfigSeed]);
+    const tournamentPda = await getGenomePda([this.tournamentSeed, idBuffer]);
+    const finishMetaDataPda = await getGenomePda([this.finishSeed, idBuffer]);
+    const roleInfoPda = await getGenomePda([this.roleSeed, verifier.toBuffer()]);
+    const stakePda = await getGenomePda([STAKE, verifier.toBuffer()]);
+    const configData = await getSingleConfig();
+    const platformWalletPda = await getGenomePda([this.platformSeed]);
+    const stakeAta = await getAssociatedTokenAddress(configData.nomeMint, stakePda, true);
+    const platformAta = await getAssociatedTokenAddress(configData.nomeMint, platformWalletPda, true);
+    return this.program.methods
+      .settleVerifier(tournamentId, verifier)
+      .accountsStrict({
+        caller,
+        config: configPda,
+        tournament: tournamentPda,
+        finishMetaData: finishMetaDataPda,
+        roleInfo: roleInfoPda,
+        stake: stakePda,
+        platformWallet: platformWalletPda,
+        nomeMint: configData.nomeMint,
+        stakeAta,
+        platformAta,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .instruction();
+  }
+
   async claimSponsorRefundIx(
     sponsor: PublicKey,
     tournamentId: number
@@ -428,17 +1195,18 @@ export class IxBuilder {
     const tournamentPda = await getGenomePda([this.tournamentSeed, idBuffer]);
     const tournament = await getTournament(tournamentId);
     const assetMint = tournament.config.assetMint;
-    const sponsorAta = await getAssociatedTokenAddress(assetMint, sponsor, true);
-    const rewardPoolAta = await getAssociatedTokenAddress(assetMint, tournamentPda, true);
+    const sponsorAta = await this.assetAta(tournament, sponsor);
+    const rewardPoolAta = await this.assetAta(tournament, tournamentPda);
     return this.program.methods
       .claimSponsorRefund(tournamentId)
       .accountsStrict({
         sponsor,
         tournament: tournamentPda,
+        sponsorship: await getGenomePda([SPONSORSHIP, idBuffer, sponsor.toBuffer()]),
         assetMint,
         sponsorAta,
         rewardPoolAta,
-        tokenProgram: TOKEN_PROGRAM_ID,
+        tokenProgram: await this.assetTokenProgram(tournament.config.assetMint),
       })
       .instruction();
   }
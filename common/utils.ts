@@ -33,6 +33,14 @@ export const PLATFORM = getConstant("platform");
 export const FINISH = getConstant("finish");
 export const CONSENSUS = getConstant("consensus");
 export const BLOOM = getConstant("bloom");
+export const BRACKET = getConstant("bracket");
+export const LEAGUE = getConstant("league");
+export const METADATA = getConstant("metadata");
+export const SPONSORSHIP = getConstant("sponsorship");
+export const BONUS = getConstant("bonus");
+export const TROPHY = getConstant("trophy");
+export const BADGE = getConstant("badge");
+export const STAKE = getConstant("stake");
 
 export type GenomeSingleConfig = IdlTypes<GenomeSolana>['genomeSingleConfig'];
 export type TournamentConfig = IdlTypes<GenomeSolana>['tournamentConfig'];
@@ -43,6 +51,13 @@ export type RoleInfo = IdlTypes<GenomeSolana>['roleInfo'];
 export type RoleList = IdlTypes<GenomeSolana>['roleList'];
 export type Role = IdlTypes<GenomeSolana>['role'];
 export type FinishMetaData = IdlTypes<GenomeSolana>['finishMetaData'];
+export type Bracket = IdlTypes<GenomeSolana>['bracket'];
+export type Sponsorship = IdlTypes<GenomeSolana>['sponsorship'];
+export type BonusPool = IdlTypes<GenomeSolana>['bonusPool'];
+export type TournamentMetadata = IdlTypes<GenomeSolana>['tournamentMetadata'];
+export type League = IdlTypes<GenomeSolana>['league'];
+export type MatchResult = IdlTypes<GenomeSolana>['matchResult'];
+export type VerifierStake = IdlTypes<GenomeSolana>['verifierStake'];
 
 /**
  * Make object pretty for logging
@@ -109,15 +124,62 @@ export async function getFinishInfo(tournamentId: number): Promise<FinishMetaDat
     return finishMetaData;
 }
 
+export async function getBracket(tournamentId: number): Promise<Bracket> {
+    const idBuffer = Buffer.alloc(4);
+    idBuffer.writeUInt32LE(tournamentId, 0);
+    const bracketPda = await getGenomePda([BRACKET, idBuffer]);
+    const bracket = await PROGRAM.account.bracket.fetch(bracketPda);
+    return bracket;
+}
+
+export async function getSponsorship(tournamentId: number, sponsor: PublicKey): Promise<Sponsorship> {
+    const idBuffer = Buffer.alloc(4);
+    idBuffer.writeUInt32LE(tournamentId, 0);
+    const sponsorshipPda = await getGenomePda([SPONSORSHIP, idBuffer, sponsor.toBuffer()]);
+    const sponsorship = await PROGRAM.account.sponsorship.fetch(sponsorshipPda);
+    return sponsorship;
+}
+
+export async function getBonusPool(tournamentId: number, mint: PublicKey): Promise<BonusPool> {
+    const idBuffer = Buffer.alloc(4);
+    idBuffer.writeUInt32LE(tournamentId, 0);
+    const bonusPoolPda = await getGenomePda([BONUS, idBuffer, mint.toBuffer()]);
+    const bonusPool = await PROGRAM.account.bonusPool.fetch(bonusPoolPda);
+    return bonusPool;
+}
+
+export async function getTournamentMetadata(tournamentId: number): Promise<TournamentMetadata> {
+    const idBuffer = Buffer.alloc(4);
+    idBuffer.writeUInt32LE(tournamentId, 0);
+    const metadataPda = await getGenomePda([METADATA, idBuffer]);
+    const metadata = await PROGRAM.account.tournamentMetadata.fetch(metadataPda);
+    return metadata;
+}
+
+export async function getLeague(tournamentId: number): Promise<League> {
+    const idBuffer = Buffer.alloc(4);
+    idBuffer.writeUInt32LE(tournamentId, 0);
+    const leaguePda = await getGenomePda([LEAGUE, idBuffer]);
+    const league = await PROGRAM.account.league.fetch(leaguePda);
+    return league;
+}
+
+export async function getVerifierStake(verifier: PublicKey): Promise<VerifierStake> {
+    const stakePda = await getGenomePda([STAKE, verifier.toBuffer()]);
+    const stake = await PROGRAM.account.verifierStake.fetch(stakePda);
+    return stake;
+}
+
 export async function getAtaInfo(
     mint: PublicKey,
-    authority: PublicKey
+    authority: PublicKey,
+    tokenProgram: PublicKey = TOKEN_PROGRAM_ID
 ): Promise<SplTokenAccount> {
     const prizePoolAta = await getAssociatedTokenAddress(
         mint,
         authority,
         true,
-        TOKEN_PROGRAM_ID,
+        tokenProgram,
         ASSOCIATED_TOKEN_PROGRAM_ID
     );
 
@@ -125,7 +187,7 @@ export async function getAtaInfo(
         getProvider().connection,
         prizePoolAta,
         undefined,
-        TOKEN_PROGRAM_ID
+        tokenProgram
     );
 }
 
@@ -0,0 +1,29 @@
+import { PublicKey } from "@solana/web3.js";
+import { getKeypairFromFile } from "@solana-developers/helpers";
+
+import { buildAndSendTx, getTeam, prettify } from "../../common/utils";
+import { IxBuilder } from "../../common/ixBuilder";
+
+async function main(): Promise<void> {
+  const args = process.argv.slice(2);
+  const [participantKeypairPath, tournamentIdStr, captainAddress] = args;
+  const participant = await getKeypairFromFile(participantKeypairPath);
+  const tournamentId = parseInt(tournamentIdStr);
+  const captain = new PublicKey(captainAddress);
+
+  const ixBuilder = new IxBuilder();
+  const acceptInviteIx = await ixBuilder.acceptInviteIx(participant.publicKey, tournamentId, captain);
+
+  const txSignature = await buildAndSendTx([acceptInviteIx], [participant]);
+  console.log("Accept invite tx signature:", txSignature);
+
+  const team = await getTeam(tournamentId, captain);
+  console.log(`Team: ${prettify(team)}`);
+}
+
+main()
+  .then(() => process.exit(0))
+  .catch((error) => {
+    console.error(error);
+    process.exit(1);
+  });
@@ -0,0 +1,31 @@
+import { BN } from "@coral-xyz/anchor";
+import { PublicKey } from "@solana/web3.js";
+import { getKeypairFromFile } from "@solana-developers/helpers";
+
+import { buildAndSendTx, getTournament } from "../../common/utils";
+import { IxBuilder } from "../../common/ixBuilder";
+
+async function main(): Promise<void> {
+  const args = process.argv.slice(2);
+  const [sponsorKeypairPath, tournamentIdStr, bonusMintStr, amountStr] = args;
+  const sponsor = await getKeypairFromFile(sponsorKeypairPath);
+  const tournamentId = parseInt(tournamentIdStr);
+  const bonusMint = new PublicKey(bonusMintStr);
+  const amount = new BN(amountStr);
+
+  const ixBuilder = new IxBuilder();
+  const addBonusPoolIx = await ixBuilder.addBonusPoolIx(sponsor.publicKey, tournamentId, bonusMint, amount);
+
+  const txSignature = await buildAndSendTx([addBonusPoolIx], [sponsor]);
+  console.log("Add bonus pool tx signature:", txSignature);
+
+  const tournament = await getTournament(tournamentId);
+  console.log("Bonus mints: ", tournament.bonusMints.map((mint) => mint.toBase58()));
+}
+
+main()
+  .then(() => process.exit(0))
+  .catch((error) => {
+    console.error(error);
+    process.exit(1);
+  });
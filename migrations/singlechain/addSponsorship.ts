@@ -0,0 +1,31 @@
+import { BN } from "@coral-xyz/anchor";
+import { getKeypairFromFile } from "@solana-developers/helpers";
+
+import { buildAndSendTx, getSponsorship, getTournament } from "../../common/utils";
+import { IxBuilder } from "../../common/ixBuilder";
+
+async function main(): Promise<void> {
+  const args = process.argv.slice(2);
+  const [sponsorKeypairPath, tournamentIdStr, amountStr] = args;
+  const sponsor = await getKeypairFromFile(sponsorKeypairPath);
+  const tournamentId = parseInt(tournamentIdStr);
+  const amount = new BN(amountStr);
+
+  const ixBuilder = new IxBuilder();
+  const addSponsorshipIx = await ixBuilder.addSponsorshipIx(sponsor.publicKey, tournamentId, amount);
+
+  const txSignature = await buildAndSendTx([addSponsorshipIx], [sponsor]);
+  console.log("Add sponsorship tx signature:", txSignature);
+
+  const sponsorship = await getSponsorship(tournamentId, sponsor.publicKey);
+  const tournament = await getTournament(tournamentId);
+  console.log("Contribution: ", sponsorship.amount.toString());
+  console.log("Sponsor pool: ", tournament.sponsorPool.toString());
+}
+
+main()
+  .then(() => process.exit(0))
+  .catch((error) => {
+    console.error(error);
+    process.exit(1);
+  });
@@ -0,0 +1,26 @@
+import { PublicKey } from "@solana/web3.js";
+import { getKeypairFromFile } from "@solana-developers/helpers";
+
+import { buildAndSendTx } from "../../common/utils";
+import { IxBuilder } from "../../common/ixBuilder";
+
+async function main(): Promise<void> {
+  const args = process.argv.slice(2);
+  const [callerKeypairPath, tournamentIdStr, bonusMintStr] = args;
+  const caller = await getKeypairFromFile(callerKeypairPath);
+  const tournamentId = parseInt(tournamentIdStr);
+  const bonusMint = new PublicKey(bonusMintStr);
+
+  const ixBuilder = new IxBuilder();
+  const closeBonusPoolIx = await ixBuilder.closeBonusPoolIx(caller.publicKey, tournamentId, bonusMint);
+
+  const txSignature = await buildAndSendTx([closeBonusPoolIx], [caller]);
+  console.log("Close bonus pool tx signature:", txSignature);
+}
+
+main()
+  .then(() => process.exit(0))
+  .catch((error) => {
+    console.error(error);
+    process.exit(1);
+  });
@@ -0,0 +1,26 @@
+import { PublicKey } from "@solana/web3.js";
+import { getKeypairFromFile } from "@solana-developers/helpers";
+
+import { buildAndSendTx } from "../../common/utils";
+import { IxBuilder } from "../../common/ixBuilder";
+
+async function main(): Promise<void> {
+  const args = process.argv.slice(2);
+  const [payerKeypairPath, tournamentIdStr, captainAddress] = args;
+  const payer = await getKeypairFromFile(payerKeypairPath);
+  const tournamentId = parseInt(tournamentIdStr);
+  const captain = new PublicKey(captainAddress);
+
+  const ixBuilder = new IxBuilder();
+  const closeTeamIx = await ixBuilder.closeTeamIx(tournamentId, captain);
+
+  const txSignature = await buildAndSendTx([closeTeamIx], [payer]);
+  console.log("Close team tx signature:", txSignature);
+}
+
+main()
+  .then(() => process.exit(0))
+  .catch((error) => {
+    console.error(error);
+    process.exit(1);
+  });
@@ -0,0 +1,24 @@
+import { getKeypairFromFile } from "@solana-developers/helpers";
+
+import { buildAndSendTx } from "../../common/utils";
+import { IxBuilder } from "../../common/ixBuilder";
+
+async function main(): Promise<void> {
+  const args = process.argv.slice(2);
+  const [callerKeypairPath, tournamentIdStr] = args;
+  const caller = await getKeypairFromFile(callerKeypairPath);
+  const tournamentId = parseInt(tournamentIdStr);
+
+  const ixBuilder = new IxBuilder();
+  const closeTournamentIx = await ixBuilder.closeTournamentIx(caller.publicKey, tournamentId);
+
+  const txSignature = await buildAndSendTx([closeTournamentIx], [caller]);
+  console.log("Close tournament tx signature:", txSignature);
+}
+
+main()
+  .then(() => process.exit(0))
+  .catch((error) => {
+    console.error(error);
+    process.exit(1);
+  });
@@ -0,0 +1,29 @@
+import { PublicKey } from "@solana/web3.js";
+import { getKeypairFromFile } from "@solana-developers/helpers";
+
+import { buildAndSendTx, getBracket, prettify } from "../../common/utils";
+import { IxBuilder } from "../../common/ixBuilder";
+
+async function main(): Promise<void> {
+  const args = process.argv.slice(2);
+  const [payerKeypairPath, tournamentIdStr, ...captainAddresses] = args;
+  const payer = await getKeypairFromFile(payerKeypairPath);
+  const tournamentId = parseInt(tournamentIdStr);
+  const captains = captainAddresses.map((address) => new PublicKey(address));
+
+  const ixBuilder = new IxBuilder();
+  const createBracketIx = await ixBuilder.createBracketIx(payer.publicKey, tournamentId, captains);
+
+  const txSignature = await buildAndSendTx([createBracketIx], [payer]);
+  console.log("Create bracket tx signature:", txSignature);
+
+  const bracket = await getBracket(tournamentId);
+  console.log(`Bracket: ${prettify(bracket)}`);
+}
+
+main()
+  .then(() => process.exit(0))
+  .catch((error) => {
+    console.error(error);
+    process.exit(1);
+  });
@@ -0,0 +1,29 @@
+import { PublicKey } from "@solana/web3.js";
+import { getKeypairFromFile } from "@solana-developers/helpers";
+
+import { buildAndSendTx, getLeague, prettify } from "../../common/utils";
+import { IxBuilder } from "../../common/ixBuilder";
+
+async function main(): Promise<void> {
+  const args = process.argv.slice(2);
+  const [payerKeypairPath, tournamentIdStr, ...captainAddresses] = args;
+  const payer = await getKeypairFromFile(payerKeypairPath);
+  const tournamentId = parseInt(tournamentIdStr);
+  const captains = captainAddresses.map((address) => new PublicKey(address));
+
+  const ixBuilder = new IxBuilder();
+  const createLeagueIx = await ixBuilder.createLeagueIx(payer.publicKey, tournamentId, captains);
+
+  const txSignature = await buildAndSendTx([createLeagueIx], [payer]);
+  console.log("Create league tx signature:", txSignature);
+
+  const league = await getLeague(tournamentId);
+  console.log(`League: ${prettify(league)}`);
+}
+
+main()
+  .then(() => process.exit(0))
+  .catch((error) => {
+    console.error(error);
+    process.exit(1);
+  });
@@ -17,6 +17,15 @@ async function main() {
         teamSizeStr,
         minTeamsStr,
         maxTeamsStr,
+        prizeDistributionStr,
+        formatStr,
+        tiebreakersStr,
+        name,
+        game,
+        rulesUri,
+        rulesHashHex,
+        claimPeriodStr,
+        nativeSolStr,
     ] = args;
 
     const organizer = await getKeypairFromFile(organizerPath);
@@ -38,6 +47,18 @@ async function main() {
             teamSize: parseInt(teamSizeStr),
             minTeams: parseInt(minTeamsStr),
             maxTeams: parseInt(maxTeamsStr),
+            prizeDistribution: prizeDistributionStr.split(",").map((share) => parseInt(share)),
+            allowlistRoot: null,
+            format: parseFormat(formatStr),
+            tiebreakers: tiebreakersStr ? tiebreakersStr.split(",").map(parseTiebreaker) : [],
+            claimPeriod: claimPeriodStr ? new BN(claimPeriodStr) : null,
+            nativeSol: nativeSolStr === "true",
+        },
+        {
+            name,
+            game,
+            rulesUri: rulesUri ?? "",
+            rulesHash: rulesHashHex ? Array.from(Buffer.from(rulesHashHex, "hex")) : Array(32).fill(0),
         }
     );
     
@@ -49,6 +70,30 @@ async function main() {
     console.log(`Tournament: ${prettify(tournament)}`);
 }
 
+function parseFormat(format: string) {
+    switch (format) {
+        case "single-elimination":
+            return { singleElimination: {} };
+        case "league":
+            return { league: {} };
+        default:
+            return { freeForm: {} };
+    }
+}
+
+function parseTiebreaker(tiebreaker: string) {
+    switch (tiebreaker) {
+        case "wins":
+            return { wins: {} };
+        case "fewest-losses":
+            return { fewestLosses: {} };
+        case "head-to-head":
+            return { headToHead: {} };
+        default:
+            throw new Error(`Unknown tiebreaker: ${tiebreaker}`);
+    }
+}
+
 main()
     .then(() => process.exit(0))
     .catch((error) => {
@@ -0,0 +1,27 @@
+import { getKeypairFromFile } from "@solana-developers/helpers";
+
+import { buildAndSendTx, getTournament } from "../../common/utils";
+import { IxBuilder } from "../../common/ixBuilder";
+
+async function main(): Promise<void> {
+  const args = process.argv.slice(2);
+  const [captainKeypairPath, tournamentIdStr] = args;
+  const captain = await getKeypairFromFile(captainKeypairPath);
+  const tournamentId = parseInt(tournamentIdStr);
+
+  const ixBuilder = new IxBuilder();
+  const disbandTeamIx = await ixBuilder.disbandTeamIx(captain.publicKey, tournamentId);
+
+  const txSignature = await buildAndSendTx([disbandTeamIx], [captain]);
+  console.log("Disband team tx signature:", txSignature);
+
+  const tournament = await getTournament(tournamentId);
+  console.log("Registered teams: ", tournament.registeredTeams)
+}
+
+main()
+  .then(() => process.exit(0))
+  .catch((error) => {
+    console.error(error);
+    process.exit(1);
+  });
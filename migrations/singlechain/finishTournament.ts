@@ -6,13 +6,13 @@ import { IxBuilder } from "../../common/ixBuilder";
 
 async function main(): Promise<void> {
     const args = process.argv.slice(2);
-    const [verifierKeypairPath, tournamentIdStr, winnerAddress] = args;
+    const [verifierKeypairPath, tournamentIdStr, placementsStr] = args;
     const verifier = await getKeypairFromFile(verifierKeypairPath);
     const tournamentId = parseInt(tournamentIdStr);
-    const winner = new PublicKey(winnerAddress);
+    const placements = placementsStr.split(",").map((address) => new PublicKey(address));
 
     const ixBuilder = new IxBuilder();
-    const finishTournamentIx = await ixBuilder.finishTournamentIx(verifier.publicKey, tournamentId, winner);
+    const finishTournamentIx = await ixBuilder.finishTournamentIx(verifier.publicKey, tournamentId, placements);
 
     const txSignature = await buildAndSendTx([finishTournamentIx], [verifier]);
     console.log("Finish tournament tx signature:", txSignature);
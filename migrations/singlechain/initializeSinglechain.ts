@@ -17,7 +17,11 @@ async function main() {
     falsePrecisionStr,
     maxOrganizerFeeStr,
     consensusRateStr,
-    nomeMintAddress
+    nomeMintAddress,
+    organizerCancelPenaltyStr,
+    claimPeriodStr,
+    stakeLockPeriodStr,
+    verifierSlashRateStr,
   ] = args;
 
   const deployer = await getKeypairFromFile(deployerPath);
@@ -36,6 +40,11 @@ async function main() {
       maxTeams: parseInt(maxTeamsStr),
       falsePrecision: new BN(falsePrecisionStr),
       consensusRate: new BN(consensusRateStr),
+      organizerCancelPenalty: new BN(organizerCancelPenaltyStr ?? 0),
+      claimPeriod: new BN(claimPeriodStr ?? 30 * 24 * 60 * 60),
+      stakeLockPeriod: new BN(stakeLockPeriodStr ?? 7 * 24 * 60 * 60),
+      totalStake: new BN(0),
+      verifierSlashRate: new BN(verifierSlashRateStr ?? 0),
       maxOrganizerFee: new BN(maxOrganizerFeeStr),
       admin,
       nomeMint,
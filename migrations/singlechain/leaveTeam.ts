@@ -0,0 +1,29 @@
+import { PublicKey } from "@solana/web3.js";
+import { getKeypairFromFile } from "@solana-developers/helpers";
+
+import { buildAndSendTx, getTournament } from "../../common/utils";
+import { IxBuilder } from "../../common/ixBuilder";
+
+async function main(): Promise<void> {
+  const args = process.argv.slice(2);
+  const [participantKeypairPath, tournamentIdStr, captainAddress] = args;
+  const participant = await getKeypairFromFile(participantKeypairPath);
+  const tournamentId = parseInt(tournamentIdStr);
+  const captain = new PublicKey(captainAddress);
+
+  const ixBuilder = new IxBuilder();
+  const leaveTeamIx = await ixBuilder.leaveTeamIx(participant.publicKey, tournamentId, captain);
+
+  const txSignature = await buildAndSendTx([leaveTeamIx], [participant]);
+  console.log("Leave team tx signature:", txSignature);
+
+  const tournament = await getTournament(tournamentId);
+  console.log("Completed teams: ", tournament.teamCount)
+}
+
+main()
+  .then(() => process.exit(0))
+  .catch((error) => {
+    console.error(error);
+    process.exit(1);
+  });
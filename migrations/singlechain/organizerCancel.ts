@@ -0,0 +1,27 @@
+import { getKeypairFromFile } from "@solana-developers/helpers";
+
+import { buildAndSendTx, getTournament } from "../../common/utils";
+import { IxBuilder } from "../../common/ixBuilder";
+
+async function main(): Promise<void> {
+  const args = process.argv.slice(2);
+  const [organizerKeypairPath, tournamentIdStr] = args;
+  const organizer = await getKeypairFromFile(organizerKeypairPath);
+  const tournamentId = parseInt(tournamentIdStr);
+
+  const ixBuilder = new IxBuilder();
+  const organizerCancelIx = await ixBuilder.organizerCancelIx(organizer.publicKey, tournamentId);
+
+  const txSignature = await buildAndSendTx([organizerCancelIx], [organizer]);
+  console.log("Organizer cancel tx signature:", txSignature);
+
+  const tournament = await getTournament(tournamentId);
+  console.log("Tournament status: ", tournament.status)
+}
+
+main()
+  .then(() => process.exit(0))
+  .catch((error) => {
+    console.error(error);
+    process.exit(1);
+  });
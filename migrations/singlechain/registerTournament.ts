@@ -1,3 +1,4 @@
+import { BN } from "@coral-xyz/anchor";
 import { PublicKey } from "@solana/web3.js";
 import { getKeypairFromFile } from "@solana-developers/helpers";
 import { buildAndSendTx, getTeam, prettify } from "../../common/utils";
@@ -5,7 +6,7 @@ import { IxBuilder } from "../../common/ixBuilder";
 
 async function main(): Promise<void> {
   const args = process.argv.slice(2);
-  const [participantPath, tournamentIdStr, captainAddress, ...teammatesAddresses] = args;
+  const [participantPath, tournamentIdStr, captainAddress, prepayStr, inviteExpirationStr, ...teammatesAddresses] = args;
   const participant = await getKeypairFromFile(participantPath);
   const tournamentId = parseInt(tournamentIdStr);
   const captain = new PublicKey(captainAddress);
@@ -16,6 +17,9 @@ async function main(): Promise<void> {
     participant: participant.publicKey,
     captain,
     teammates,
+    prepay: prepayStr === "true",
+    inviteExpiration: new BN(inviteExpirationStr),
+    proofs: [],
   };
 
   const ixBuilder = new IxBuilder();
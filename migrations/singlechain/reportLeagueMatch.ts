@@ -0,0 +1,42 @@
+import { getKeypairFromFile } from "@solana-developers/helpers";
+
+import { buildAndSendTx, getLeague, MatchResult } from "../../common/utils";
+import { IxBuilder } from "../../common/ixBuilder";
+
+async function main(): Promise<void> {
+  const args = process.argv.slice(2);
+  const [verifierKeypairPath, tournamentIdStr, matchIndexStr, resultStr] = args;
+  const verifier = await getKeypairFromFile(verifierKeypairPath);
+  const tournamentId = parseInt(tournamentIdStr);
+  const matchIndex = parseInt(matchIndexStr);
+  const result = parseResult(resultStr);
+
+  const ixBuilder = new IxBuilder();
+  const reportLeagueMatchIx = await ixBuilder.reportLeagueMatchIx(verifier.publicKey, tournamentId, matchIndex, result);
+
+  const txSignature = await buildAndSendTx([reportLeagueMatchIx], [verifier]);
+  console.log("Report league match tx signature:", txSignature);
+
+  const league = await getLeague(tournamentId);
+  console.log("Match: ", league.matches[matchIndex])
+}
+
+function parseResult(result: string): MatchResult {
+  switch (result) {
+    case "home":
+      return { homeWin: {} };
+    case "away":
+      return { awayWin: {} };
+    case "draw":
+      return { draw: {} };
+    default:
+      throw new Error(`Unknown match result: ${result}`);
+  }
+}
+
+main()
+  .then(() => process.exit(0))
+  .catch((error) => {
+    console.error(error);
+    process.exit(1);
+  });
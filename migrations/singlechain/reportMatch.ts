@@ -0,0 +1,30 @@
+import { PublicKey } from "@solana/web3.js";
+import { getKeypairFromFile } from "@solana-developers/helpers";
+
+import { buildAndSendTx, getBracket } from "../../common/utils";
+import { IxBuilder } from "../../common/ixBuilder";
+
+async function main(): Promise<void> {
+  const args = process.argv.slice(2);
+  const [verifierKeypairPath, tournamentIdStr, matchIndexStr, winnerAddress] = args;
+  const verifier = await getKeypairFromFile(verifierKeypairPath);
+  const tournamentId = parseInt(tournamentIdStr);
+  const matchIndex = parseInt(matchIndexStr);
+  const winner = new PublicKey(winnerAddress);
+
+  const ixBuilder = new IxBuilder();
+  const reportMatchIx = await ixBuilder.reportMatchIx(verifier.publicKey, tournamentId, matchIndex, winner);
+
+  const txSignature = await buildAndSendTx([reportMatchIx], [verifier]);
+  console.log("Report match tx signature:", txSignature);
+
+  const bracket = await getBracket(tournamentId);
+  console.log("Match: ", bracket.matches[matchIndex])
+}
+
+main()
+  .then(() => process.exit(0))
+  .catch((error) => {
+    console.error(error);
+    process.exit(1);
+  });
@@ -0,0 +1,28 @@
+import { getKeypairFromFile } from "@solana-developers/helpers";
+
+import { buildAndSendTx, getTournament } from "../../common/utils";
+import { IxBuilder } from "../../common/ixBuilder";
+
+async function main(): Promise<void> {
+  const args = process.argv.slice(2);
+  const [organizerKeypairPath, tournamentIdStr, allowlistRootHex] = args;
+  const organizer = await getKeypairFromFile(organizerKeypairPath);
+  const tournamentId = parseInt(tournamentIdStr);
+  const allowlistRoot = allowlistRootHex ? Array.from(Buffer.from(allowlistRootHex, "hex")) : null;
+
+  const ixBuilder = new IxBuilder();
+  const setAllowlistIx = await ixBuilder.setAllowlistIx(organizer.publicKey, tournamentId, allowlistRoot);
+
+  const txSignature = await buildAndSendTx([setAllowlistIx], [organizer]);
+  console.log("Set allowlist tx signature:", txSignature);
+
+  const tournament = await getTournament(tournamentId);
+  console.log("Allowlist root: ", tournament.config.allowlistRoot)
+}
+
+main()
+  .then(() => process.exit(0))
+  .catch((error) => {
+    console.error(error);
+    process.exit(1);
+  });
@@ -0,0 +1,26 @@
+import { BN } from "@coral-xyz/anchor";
+import { getKeypairFromFile } from "@solana-developers/helpers";
+import { buildAndSendTx } from "../../common/utils";
+import { IxBuilder } from "../../common/ixBuilder";
+
+async function main(): Promise<void> {
+  const args = process.argv.slice(2);
+  const [adminKeypairPath, penaltyStr] = args;
+  const admin = await getKeypairFromFile(adminKeypairPath);
+  const penalty = new BN(penaltyStr);
+
+  const ixBuilder = new IxBuilder();
+  const setCancelPenaltyIx = await ixBuilder.setCancelPenaltyIx(
+    admin.publicKey,
+    penalty
+  );
+  const txSignature = await buildAndSendTx([setCancelPenaltyIx], [admin]);
+  console.log("Set cancel penalty tx signature:", txSignature);
+}
+
+main()
+  .then(() => process.exit(0))
+  .catch((err) => {
+    console.error(err);
+    process.exit(1);
+  });
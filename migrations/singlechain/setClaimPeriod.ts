@@ -0,0 +1,26 @@
+import { BN } from "@coral-xyz/anchor";
+import { getKeypairFromFile } from "@solana-developers/helpers";
+import { buildAndSendTx } from "../../common/utils";
+import { IxBuilder } from "../../common/ixBuilder";
+
+async function main(): Promise<void> {
+  const args = process.argv.slice(2);
+  const [adminKeypairPath, claimPeriodStr] = args;
+  const admin = await getKeypairFromFile(adminKeypairPath);
+  const claimPeriod = new BN(claimPeriodStr);
+
+  const ixBuilder = new IxBuilder();
+  const setClaimPeriodIx = await ixBuilder.setClaimPeriodIx(
+    admin.publicKey,
+    claimPeriod
+  );
+  const txSignature = await buildAndSendTx([setClaimPeriodIx], [admin]);
+  console.log("Set claim period tx signature:", txSignature);
+}
+
+main()
+  .then(() => process.exit(0))
+  .catch((err) => {
+    console.error(err);
+    process.exit(1);
+  });
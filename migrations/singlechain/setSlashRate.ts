@@ -0,0 +1,26 @@
+import { BN } from "@coral-xyz/anchor";
+import { getKeypairFromFile } from "@solana-developers/helpers";
+import { buildAndSendTx } from "../../common/utils";
+import { IxBuilder } from "../../common/ixBuilder";
+
+async function main(): Promise<void> {
+  const args = process.argv.slice(2);
+  const [adminKeypairPath, slashRateStr] = args;
+  const admin = await getKeypairFromFile(adminKeypairPath);
+  const slashRate = new BN(slashRateStr);
+
+  const ixBuilder = new IxBuilder();
+  const setSlashRateIx = await ixBuilder.setSlashRateIx(
+    admin.publicKey,
+    slashRate
+  );
+  const txSignature = await buildAndSendTx([setSlashRateIx], [admin]);
+  console.log("Set slash rate tx signature:", txSignature);
+}
+
+main()
+  .then(() => process.exit(0))
+  .catch((err) => {
+    console.error(err);
+    process.exit(1);
+  });
@@ -0,0 +1,26 @@
+import { PublicKey } from "@solana/web3.js";
+import { getKeypairFromFile } from "@solana-developers/helpers";
+
+import { buildAndSendTx } from "../../common/utils";
+import { IxBuilder } from "../../common/ixBuilder";
+
+async function main(): Promise<void> {
+  const args = process.argv.slice(2);
+  const [callerKeypairPath, tournamentIdStr, verifierAddress] = args;
+  const caller = await getKeypairFromFile(callerKeypairPath);
+  const tournamentId = parseInt(tournamentIdStr);
+  const verifier = new PublicKey(verifierAddress);
+
+  const ixBuilder = new IxBuilder();
+  const settleVerifierIx = await ixBuilder.settleVerifierIx(caller.publicKey, tournamentId, verifier);
+
+  const txSignature = await buildAndSendTx([settleVerifierIx], [caller]);
+  console.log("Settle verifier tx signature:", txSignature);
+}
+
+main()
+  .then(() => process.exit(0))
+  .catch((error) => {
+    console.error(error);
+    process.exit(1);
+  });
@@ -0,0 +1,27 @@
+import { BN } from "@coral-xyz/anchor";
+import { getKeypairFromFile } from "@solana-developers/helpers";
+
+import { buildAndSendTx } from "../../common/utils";
+import { IxBuilder } from "../../common/ixBuilder";
+
+async function main(): Promise<void> {
+  const args = process.argv.slice(2);
+  const [verifierKeypairPath, amountStr] = args;
+  const verifier = await getKeypairFromFile(verifierKeypairPath);
+  const amount = new BN(amountStr);
+
+  const ixBuilder = new IxBuilder();
+  const unstakeIx = await ixBuilder.unstakeIx(
+    verifier.publicKey,
+    amount
+  );
+  const txSignature = await buildAndSendTx([unstakeIx], [verifier]);
+  console.log("Unstake tx signature:", txSignature);
+}
+
+main()
+  .then(() => process.exit(0))
+  .catch((err) => {
+    console.error(err);
+    process.exit(1);
+  });
@@ -0,0 +1,48 @@
+import { BN } from "@coral-xyz/anchor";
+import { getKeypairFromFile } from "@solana-developers/helpers";
+
+import { buildAndSendTx, getTournament, prettify } from "../../common/utils";
+import { IxBuilder } from "../../common/ixBuilder";
+
+async function main(): Promise<void> {
+  const args = process.argv.slice(2);
+  const [
+    organizerKeypairPath,
+    tournamentIdStr,
+    organizerFeeStr,
+    expirationTimeStr,
+    entryFeeStr,
+    teamSizeStr,
+    minTeamsStr,
+    maxTeamsStr,
+    prizeDistributionStr,
+  ] = args;
+  const organizer = await getKeypairFromFile(organizerKeypairPath);
+  const tournamentId = parseInt(tournamentIdStr);
+  const tournament = await getTournament(tournamentId);
+
+  const ixBuilder = new IxBuilder();
+  const updateTournamentIx = await ixBuilder.updateTournamentIx(organizer.publicKey, tournamentId, {
+    ...tournament.config,
+    organizerFee: new BN(organizerFeeStr),
+    expirationTime: new BN(expirationTimeStr),
+    entryFee: new BN(entryFeeStr),
+    teamSize: parseInt(teamSizeStr),
+    minTeams: parseInt(minTeamsStr),
+    maxTeams: parseInt(maxTeamsStr),
+    prizeDistribution: prizeDistributionStr.split(",").map((share) => parseInt(share)),
+  });
+
+  const txSignature = await buildAndSendTx([updateTournamentIx], [organizer]);
+  console.log("Update tournament tx signature:", txSignature);
+
+  const updated = await getTournament(tournamentId);
+  console.log(`Tournament: ${prettify(updated)}`);
+}
+
+main()
+  .then(() => process.exit(0))
+  .catch((error) => {
+    console.error(error);
+    process.exit(1);
+  });
@@ -0,0 +1,32 @@
+import { getKeypairFromFile } from "@solana-developers/helpers";
+
+import { buildAndSendTx, getTournamentMetadata, prettify } from "../../common/utils";
+import { IxBuilder } from "../../common/ixBuilder";
+
+async function main(): Promise<void> {
+  const args = process.argv.slice(2);
+  const [organizerKeypairPath, tournamentIdStr, name, game, rulesUri, rulesHashHex] = args;
+  const organizer = await getKeypairFromFile(organizerKeypairPath);
+  const tournamentId = parseInt(tournamentIdStr);
+
+  const ixBuilder = new IxBuilder();
+  const updateMetadataIx = await ixBuilder.updateTournamentMetadataIx(organizer.publicKey, tournamentId, {
+    name,
+    game,
+    rulesUri,
+    rulesHash: Array.from(Buffer.from(rulesHashHex, "hex")),
+  });
+
+  const txSignature = await buildAndSendTx([updateMetadataIx], [organizer]);
+  console.log("Update tournament metadata tx signature:", txSignature);
+
+  const metadata = await getTournamentMetadata(tournamentId);
+  console.log(`Metadata: ${prettify(metadata)}`);
+}
+
+main()
+  .then(() => process.exit(0))
+  .catch((error) => {
+    console.error(error);
+    process.exit(1);
+  });
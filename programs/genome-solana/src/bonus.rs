@@ -0,0 +1,118 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{transfer_checked, Mint, TokenAccount, TransferChecked};
+
+use crate::{data::Tournament, error::GenomeError, fees::member_reward};
+
+/// Bonus prize in a mint other than the tournament asset. It is split between the placements
+/// by the same prize table as the main reward pool, without the organizer fee.
+#[account]
+#[derive(InitSpace)]
+pub(crate) struct BonusPool {
+    pub(crate) tournament_id: u32,
+    pub(crate) sponsor: Pubkey,
+    pub(crate) mint: Pubkey,
+    pub(crate) amount: u64,
+}
+
+impl BonusPool {
+    pub(crate) const MAX_BONUS_POOLS: usize = 3;
+
+    pub(crate) fn reward(&self, share: u16, team_size: u16) -> Result<u64> {
+        member_reward(self.amount, share, team_size)
+    }
+}
+
+pub(crate) struct BonusPayout<'info> {
+    pool: Account<'info, BonusPool>,
+    mint: InterfaceAccount<'info, Mint>,
+    pool_ata: AccountInfo<'info>,
+}
+
+/// Loads the bonus pools of the tournament from `accounts`, which holds the bonus pool, its
+/// mint and its token account for every entry of `Tournament::bonus_mints`, in that order.
+pub(crate) fn load_bonus_payouts<'info>(
+    tournament: &Account<'info, Tournament>,
+    accounts: &'info [AccountInfo<'info>],
+) -> Result<Vec<BonusPayout<'info>>> {
+    require!(accounts.len() == 3 * tournament.bonus_mints.len(), GenomeError::InvalidBonusPool);
+
+    let mut payouts = Vec::with_capacity(tournament.bonus_mints.len());
+    for (bonus_mint, chunk) in tournament.bonus_mints.iter().zip(accounts.chunks(3)) {
+        let pool = Account::<BonusPool>::try_from(&chunk[0])?;
+        let mint = InterfaceAccount::<Mint>::try_from(&chunk[1])?;
+        let pool_ata = InterfaceAccount::<TokenAccount>::try_from(&chunk[2])?;
+        require!(
+            pool.tournament_id == tournament.id
+                && pool.mint == *bonus_mint
+                && mint.key() == *bonus_mint
+                && pool_ata.mint == *bonus_mint
+                && pool_ata.owner == tournament.key(),
+            GenomeError::InvalidBonusPool
+        );
+
+        payouts.push(BonusPayout {
+            pool,
+            mint,
+            pool_ata: chunk[2].clone(),
+        });
+    }
+
+    Ok(payouts)
+}
+
+/// Pays every bonus prize of a placement to one participant. `recipients` holds the
+/// participant's token account for every bonus pool, in the same order as `payouts`.
+pub(crate) fn pay_bonuses<'info>(
+    payouts: &[BonusPayout<'info>],
+    recipients: &'info [AccountInfo<'info>],
+    participant: &Pubkey,
+    share: u16,
+    tournament: &Account<'info, Tournament>,
+    token_program: AccountInfo<'info>,
+    signer: &[&[&[u8]]],
+) -> Result<()> {
+    require!(recipients.len() == payouts.len(), GenomeError::InvalidBonusPool);
+
+    for (payout, recipient) in payouts.iter().zip(recipients) {
+        let recipient_ata = InterfaceAccount::<TokenAccount>::try_from(recipient)?;
+        require!(
+            recipient_ata.owner == *participant && recipient_ata.mint == payout.pool.mint,
+            GenomeError::InvalidBonusPool
+        );
+
+        let amount = payout.pool.reward(share, tournament.config.team_size)?;
+        if amount == 0 {
+            continue;
+        }
+
+        let accounts = TransferChecked {
+            from: payout.pool_ata.clone(),
+            to: recipient.clone(),
+            mint: payout.mint.to_account_info(),
+            authority: tournament.to_account_info(),
+        };
+        let cpi = CpiContext::new_with_signer(token_program.clone(), accounts, signer);
+        transfer_checked(cpi, amount, payout.mint.decimals)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bonus_reward_split() {
+        let pool = BonusPool {
+            tournament_id: 0,
+            sponsor: Pubkey::new_unique(),
+            mint: Pubkey::new_unique(),
+            amount: 1000,
+        };
+
+        assert_eq!(pool.reward(7000, 2).unwrap(), 350);
+        assert_eq!(pool.reward(3000, 3).unwrap(), 100);
+        assert_eq!(pool.reward(10000, 1).unwrap(), 1000);
+    }
+}
@@ -0,0 +1,198 @@
+use crate::error::GenomeError;
+use anchor_lang::prelude::*;
+
+#[derive(Default, Clone, AnchorDeserialize, AnchorSerialize, Debug, PartialEq, Eq, InitSpace)]
+pub(crate) struct Match {
+    pub(crate) home: Option<Pubkey>,
+    pub(crate) away: Option<Pubkey>,
+    pub(crate) winner: Option<Pubkey>,
+    pub(crate) home_votes: u64,
+    pub(crate) away_votes: u64,
+}
+
+impl Match {
+    pub(crate) fn loser(&self) -> Option<Pubkey> {
+        match self.winner {
+            Some(winner) if self.home == Some(winner) => self.away,
+            Some(_) => self.home,
+            None => None,
+        }
+    }
+}
+
+/// Single-elimination bracket stored as a flat list of matches. The first round takes
+/// `size / 2` matches, and the winner of match `i` moves to match `size / 2 + i / 2`,
+/// so the final is always the last match.
+#[account]
+#[derive(InitSpace, Debug)]
+pub(crate) struct Bracket {
+    pub(crate) payer: Pubkey,
+    pub(crate) size: u16,
+    #[max_len(0)]
+    pub(crate) matches: Vec<Match>,
+}
+
+impl Bracket {
+    pub(crate) const MAX_TEAMS: usize = 64;
+
+    pub(crate) fn size_for(teams_count: usize) -> usize {
+        teams_count.next_power_of_two().max(2)
+    }
+
+    pub(crate) fn space(teams_count: usize) -> usize {
+        Self::DISCRIMINATOR.len()
+            + Self::INIT_SPACE
+            + Match::INIT_SPACE * (Self::size_for(teams_count) - 1)
+    }
+
+    /// Seeds the first round with `captains`, pairing the first half against the second one.
+    /// Captains left without an opponent get a bye and advance immediately.
+    pub(crate) fn new(captains: &[Pubkey]) -> Self {
+        let size = Self::size_for(captains.len());
+        let half = size / 2;
+        let mut bracket = Self {
+            payer: Pubkey::default(),
+            size: size as u16,
+            matches: vec![Match::default(); size - 1],
+        };
+
+        for index in 0..half {
+            let home = captains.get(index).copied();
+            let away = captains.get(index + half).copied();
+            bracket.matches[index].home = home;
+            bracket.matches[index].away = away;
+
+            if let (Some(home), None) = (home, away) {
+                bracket.advance(index, home);
+            }
+        }
+
+        bracket
+    }
+
+    pub(crate) fn vote(
+        &mut self,
+        match_index: usize,
+        winner: Pubkey,
+        verifier_index: usize,
+    ) -> Result<u64> {
+        let game = self.matches.get_mut(match_index).ok_or(GenomeError::InvalidMatch)?;
+        require!(game.winner.is_none(), GenomeError::InvalidMatch);
+        require!(game.home.is_some() && game.away.is_some(), GenomeError::InvalidMatch);
+        require!(
+            ((game.home_votes | game.away_votes) >> verifier_index) & 1 == 0,
+            GenomeError::AlreadyVoted
+        );
+
+        let votes = if game.home == Some(winner) {
+            &mut game.home_votes
+        } else if game.away == Some(winner) {
+            &mut game.away_votes
+        } else {
+            return Err(GenomeError::InvalidMatch.into());
+        };
+        *votes |= 1 << verifier_index;
+
+        Ok(votes.count_ones() as u64)
+    }
+
+    pub(crate) fn advance(&mut self, match_index: usize, winner: Pubkey) {
+        self.matches[match_index].winner = Some(winner);
+
+        if match_index + 1 == self.matches.len() {
+            return;
+        }
+
+        let next = &mut self.matches[self.size as usize / 2 + match_index / 2];
+        if match_index % 2 == 1 {
+            next.away = Some(winner);
+        } else {
+            next.home = Some(winner);
+        }
+    }
+
+    pub(crate) fn champion(&self) -> Option<Pubkey> {
+        self.matches.last().and_then(|game| game.winner)
+    }
+
+    /// Returns the first `places_count` captains ordered by placement. Teams knocked out in
+    /// the same round share a rank and are ordered by match index.
+    pub(crate) fn placements(&self, places_count: usize) -> Option<Vec<Pubkey>> {
+        let mut placements = vec![self.champion()?];
+        placements.extend(self.matches.iter().rev().filter_map(Match::loser));
+        placements.truncate(places_count);
+        Some(placements)
+    }
+
+    /// Prize shares of teams knocked out in the same round must be equal, since the bracket
+    /// doesn't rank them against each other.
+    pub(crate) fn validate_prize_distribution(prize_distribution: &[u16]) -> bool {
+        let mut start = 2;
+        let mut round_len = 2;
+        while start < prize_distribution.len() {
+            let end = (start + round_len).min(prize_distribution.len());
+            let round = &prize_distribution[start..end];
+            if round.iter().any(|&share| share != round[0]) {
+                return false;
+            }
+            start = end;
+            round_len *= 2;
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn captains(count: usize) -> Vec<Pubkey> {
+        (0..count).map(|_| Pubkey::new_unique()).collect()
+    }
+
+    #[test]
+    fn test_bracket_byes_advance() {
+        let teams = captains(3);
+        let bracket = Bracket::new(&teams);
+
+        assert_eq!(bracket.size, 4);
+        assert_eq!(bracket.matches.len(), 3);
+        assert_eq!(bracket.matches[0].away, Some(teams[2]));
+        assert_eq!(bracket.matches[1].winner, Some(teams[1]));
+        assert_eq!(bracket.matches[2].away, Some(teams[1]));
+    }
+
+    #[test]
+    fn test_bracket_placements() {
+        let teams = captains(4);
+        let mut bracket = Bracket::new(&teams);
+
+        bracket.advance(0, teams[0]);
+        bracket.advance(1, teams[3]);
+        assert_eq!(bracket.placements(4), None);
+
+        bracket.advance(2, teams[3]);
+        assert_eq!(bracket.champion(), Some(teams[3]));
+        assert_eq!(bracket.placements(4), Some(vec![teams[3], teams[0], teams[1], teams[2]]));
+        assert_eq!(bracket.placements(2), Some(vec![teams[3], teams[0]]));
+    }
+
+    #[test]
+    fn test_bracket_vote() {
+        let teams = captains(2);
+        let mut bracket = Bracket::new(&teams);
+
+        assert_eq!(bracket.vote(0, teams[1], 0).unwrap(), 1);
+        assert_eq!(bracket.vote(0, teams[1], 2).unwrap(), 2);
+        assert!(bracket.vote(0, teams[0], 0).is_err());
+        assert!(bracket.vote(0, Pubkey::new_unique(), 1).is_err());
+        assert!(bracket.vote(1, teams[0], 1).is_err());
+    }
+
+    #[test]
+    fn test_bracket_prize_distribution() {
+        assert!(Bracket::validate_prize_distribution(&[6000, 3000, 500, 500]));
+        assert!(Bracket::validate_prize_distribution(&[7000, 3000]));
+        assert!(!Bracket::validate_prize_distribution(&[6000, 2000, 1500, 500]));
+    }
+}
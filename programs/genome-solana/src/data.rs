@@ -1,4 +1,6 @@
-use anchor_lang::prelude::*;
+use anchor_lang::{prelude::*, solana_program::pubkey::PUBKEY_BYTES};
+
+use crate::{error::GenomeError, league::Tiebreaker};
 
 #[account]
 #[derive(InitSpace)]
@@ -23,6 +25,15 @@ pub(crate) struct GenomeSingleConfig {
     pub(crate) max_organizer_fee: u64,
     pub(crate) min_teams: u16,
     pub(crate) max_teams: u16,
+    pub(crate) organizer_cancel_penalty: u64,
+    pub(crate) claim_period: u64,
+    /// How long a verifier's stake stays locked after their last vote.
+    pub(crate) stake_lock_period: u64,
+    /// NOME staked by all verifiers, the total weight consensus is measured against.
+    pub(crate) total_stake: u64,
+    /// Share of the stake, in basis points, slashed from a verifier whose finish vote backed
+    /// a captain that didn't win.
+    pub(crate) verifier_slash_rate: u64,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
@@ -36,6 +47,29 @@ pub(crate) struct TournamentConfig {
     pub(crate) max_teams: u16,
     pub(crate) sponsor: Pubkey,
     pub(crate) asset_mint: Pubkey,
+    #[max_len(8)]
+    pub(crate) prize_distribution: Vec<u16>,
+    pub(crate) allowlist_root: Option<[u8; 32]>,
+    pub(crate) format: TournamentFormat,
+    #[max_len(3)]
+    pub(crate) tiebreakers: Vec<Tiebreaker>,
+    pub(crate) claim_period: Option<u64>,
+    /// Entry fees and sponsor pools are paid in SOL and held as lamports of the tournament
+    /// PDA. The asset mint has to be the native mint then.
+    pub(crate) native_sol: bool,
+}
+
+impl TournamentConfig {
+    pub(crate) const MAX_PRIZE_PLACES: usize = 8;
+    /// Longest claim period a tournament or the platform can set, ten years.
+    pub(crate) const MAX_CLAIM_PERIOD: u64 = 10 * 365 * 24 * 60 * 60;
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, PartialEq, Clone, InitSpace)]
+pub(crate) enum TournamentFormat {
+    FreeForm,
+    SingleElimination,
+    League,
 }
 
 #[account]
@@ -44,8 +78,24 @@ pub(crate) struct Tournament {
     pub(crate) id: u32,
     pub(crate) organizer: Pubkey,
     pub(crate) team_count: u32,
+    pub(crate) registered_teams: u32,
     pub(crate) config: TournamentConfig,
     pub(crate) status: TournamentStatus,
+    pub(crate) sponsor_pool: u64,
+    /// Net entry fees of the completed teams, which go to the prize pool on finish.
+    pub(crate) entry_pool: u64,
+    /// Everything that reached the reward pool, sponsorships and entry fees alike.
+    pub(crate) total_deposited: u64,
+    pub(crate) entry_fees: u64,
+    pub(crate) refunds_paid: u64,
+    pub(crate) rewards_paid: u64,
+    pub(crate) organizer_fee_paid: u64,
+    pub(crate) unclaimed_swept: u64,
+    pub(crate) claim_deadline: u64,
+    #[max_len(3)]
+    pub(crate) bonus_mints: Vec<Pubkey>,
+    /// Sponsorship accounts that are not closed yet.
+    pub(crate) sponsorships: u32,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, PartialEq, Clone, InitSpace)]
@@ -62,15 +112,158 @@ impl Tournament {
         self.organizer = organizer;
         self.config = tournament_config;
     }
+
+    /// Moves the tournament to its final status and opens the claim window, using the
+    /// tournament's own claim period when it has one.
+    pub fn settle(
+        &mut self,
+        status: TournamentStatus,
+        default_claim_period: u64,
+        now: u64,
+    ) -> Result<()> {
+        self.status = status;
+        self.claim_deadline = now
+            .checked_add(self.config.claim_period.unwrap_or(default_claim_period))
+            .ok_or(GenomeError::MathOverflow)?;
+        Ok(())
+    }
+
+    pub fn claims_open(&self, now: u64) -> bool {
+        self.claim_deadline == 0 || now < self.claim_deadline
+    }
+
+    /// A new tournament expires at `expiration_time`. A started one that verifiers didn't
+    /// finish expires one claim period later, so finish votes on it can still be settled.
+    pub fn expired(&self, default_claim_period: u64, now: u64) -> bool {
+        match self.status {
+            TournamentStatus::New => now >= self.config.expiration_time,
+            TournamentStatus::Started => {
+                let finish_period = self.config.claim_period.unwrap_or(default_claim_period);
+                now >= self.config.expiration_time.saturating_add(finish_period)
+            }
+            _ => false,
+        }
+    }
+
+    pub fn record_sponsorship(&mut self, amount: u64) {
+        self.sponsor_pool += amount;
+        self.total_deposited += amount;
+    }
+
+    pub fn record_entry_fee(&mut self, amount: u64) {
+        self.entry_fees += amount;
+        self.total_deposited += amount;
+    }
+
+    /// Amount the ledger holds in escrow for the tournament.
+    pub fn escrow_balance(&self) -> Result<u64> {
+        [
+            self.refunds_paid,
+            self.rewards_paid,
+            self.organizer_fee_paid,
+            self.unclaimed_swept,
+        ]
+        .iter()
+        .try_fold(self.total_deposited, |balance, &paid| balance.checked_sub(paid))
+        .ok_or(GenomeError::MathOverflow.into())
+    }
+
+    /// Books a payout out of the escrow, failing if the ledger doesn't cover it.
+    pub fn record_payout(&mut self, payout: Payout, amount: u64) -> Result<()> {
+        require!(self.escrow_balance()? >= amount, GenomeError::InsufficientFunds);
+        let paid = match payout {
+            Payout::Refund => &mut self.refunds_paid,
+            Payout::Reward => &mut self.rewards_paid,
+            Payout::OrganizerFee => &mut self.organizer_fee_paid,
+            Payout::Unclaimed => &mut self.unclaimed_swept,
+        };
+        *paid += amount;
+        Ok(())
+    }
+}
+
+pub(crate) enum Payout {
+    Refund,
+    Reward,
+    OrganizerFee,
+    Unclaimed,
+}
+
+/// Contribution of a single sponsor to the tournament reward pool.
+#[account]
+#[derive(InitSpace)]
+pub(crate) struct Sponsorship {
+    pub(crate) sponsor: Pubkey,
+    pub(crate) amount: u64,
+    pub(crate) refunded: bool,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub(crate) struct TournamentMetadata {
+    #[max_len(64)]
+    pub(crate) name: String,
+    #[max_len(32)]
+    pub(crate) game: String,
+    #[max_len(200)]
+    pub(crate) rules_uri: String,
+    pub(crate) rules_hash: [u8; 32],
+}
+
+impl TournamentMetadata {
+    pub(crate) const MAX_NAME_LEN: usize = 64;
+    pub(crate) const MAX_GAME_LEN: usize = 32;
+    pub(crate) const MAX_RULES_URI_LEN: usize = 200;
 }
 
 #[account]
 #[derive(InitSpace)]
 pub(crate) struct FinishMetaData {
+    #[max_len(0, 0)]
+    pub(crate) finish_votes: Vec<Vec<Pubkey>>,
+    #[max_len(0)]
+    pub(crate) placements: Vec<Pubkey>,
+    #[max_len(0)]
+    pub(crate) rewards: Vec<u64>,
+    /// Stake weight of every vote in `finish_votes`.
+    #[max_len(0)]
+    pub(crate) vote_weights: Vec<u64>,
+    /// Verifier who cast every vote in `finish_votes`.
     #[max_len(0)]
-    pub(crate) finish_votes: Vec<Pubkey>,
-    pub(crate) captain_winner: Pubkey,
-    pub(crate) reward: u64,
+    pub(crate) voters: Vec<Pubkey>,
+    /// Bitmask of the votes whose verifiers are settled.
+    pub(crate) settled: u64,
+}
+
+impl FinishMetaData {
+    pub(crate) fn space(verifiers_count: usize, places_count: usize) -> usize {
+        let vote_space = 4 + PUBKEY_BYTES * places_count + 8 + PUBKEY_BYTES;
+        Self::DISCRIMINATOR.len()
+            + Self::INIT_SPACE
+            + vote_space * verifiers_count
+            + (PUBKEY_BYTES + 8) * places_count
+    }
+
+    pub(crate) fn place_of(&self, captain: &Pubkey) -> Option<usize> {
+        self.placements.iter().position(|c| c == captain)
+    }
+
+    pub(crate) fn reward_of(&self, captain: &Pubkey) -> Option<u64> {
+        self.place_of(captain).map(|place| self.rewards[place])
+    }
+
+    pub(crate) fn vote_of(&self, verifier: &Pubkey) -> Option<usize> {
+        self.voters.iter().position(|v| v == verifier)
+    }
+
+    /// Whether the vote put the captain that won the tournament first.
+    pub(crate) fn backed_winner(&self, vote: usize) -> bool {
+        self.finish_votes[vote].first() == self.placements.first()
+    }
+
+    pub(crate) fn all_settled(&self) -> bool {
+        self.settled.count_ones() as usize == self.voters.len()
+    }
 }
 
 #[account]
@@ -124,11 +317,45 @@ pub(crate) struct Consensus {
     pub(crate) start_votes: u64,
     pub(crate) cancel_votes: u64,
     pub(crate) finish_votes: u64,
+    pub(crate) start_weight: u64,
+    pub(crate) cancel_weight: u64,
+    pub(crate) finish_weight: u64,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub(crate) struct VerifierStake {
+    pub(crate) verifier: Pubkey,
+    pub(crate) amount: u64,
+    pub(crate) locked_until: u64,
+    /// Whether the stake counts towards the config's `total_stake`. Revoking the Verifier role
+    /// takes it out until the verifier stakes again.
+    pub(crate) active: bool,
+    /// Finish votes that are not settled yet. The stake can't be withdrawn until they are,
+    /// so a slash can't be dodged.
+    pub(crate) unsettled_votes: u32,
+}
+
+impl VerifierStake {
+    /// Weight of a vote cast at `now`. The stake stays locked for `lock_period` after the
+    /// vote, so it can't be moved to another verifier and counted twice.
+    pub(crate) fn vote_weight(&mut self, now: u64, lock_period: u64) -> Result<u64> {
+        require!(self.active && self.amount > 0, GenomeError::NoStake);
+        self.locked_until = now.checked_add(lock_period).ok_or(GenomeError::MathOverflow)?;
+        Ok(self.amount)
+    }
 }
 
 #[account]
 pub(crate) struct BloomFilter {
     pub(crate) data: Vec<u8>,
+    pub(crate) released: Vec<Pubkey>,
+}
+
+impl BloomFilter {
+    pub(crate) fn space(bloom_memory: usize) -> usize {
+        Self::DISCRIMINATOR.len() + bloom_memory + 4
+    }
 }
 
 #[cfg(test)]
@@ -156,7 +383,10 @@ mod tests {
     fn test_bloom_filter_account_insertion() {
         let bloom = GrowableBloom::new(FALSE_PRECISION, SIZE);
         let bloom_bytes = bincode::serialize(&bloom).unwrap();
-        let mut bloom_filter_account = BloomFilter { data: bloom_bytes };
+        let mut bloom_filter_account = BloomFilter {
+            data: bloom_bytes,
+            released: vec![],
+        };
 
         let mut bloom_loaded: GrowableBloom =
             bincode::deserialize(&bloom_filter_account.data).unwrap();
@@ -195,4 +425,114 @@ mod tests {
         let observed_fp_rate = false_positives as f64 / test_count as f64;
         assert!(observed_fp_rate < FALSE_PRECISION);
     }
+
+    #[test]
+    fn test_finish_meta_data_reward_by_placement() {
+        let first = Pubkey::new_unique();
+        let second = Pubkey::new_unique();
+        let finish_meta_data = FinishMetaData {
+            finish_votes: vec![],
+            placements: vec![first, second],
+            rewards: vec![700, 300],
+            vote_weights: vec![],
+            voters: vec![],
+            settled: 0,
+        };
+
+        assert_eq!(finish_meta_data.reward_of(&first), Some(700));
+        assert_eq!(finish_meta_data.reward_of(&second), Some(300));
+        assert_eq!(finish_meta_data.reward_of(&Pubkey::new_unique()), None);
+    }
+
+    #[test]
+    fn test_finish_meta_data_backed_winner() {
+        let first = Pubkey::new_unique();
+        let second = Pubkey::new_unique();
+        let voters = vec![Pubkey::new_unique(), Pubkey::new_unique()];
+        let mut finish_meta_data = FinishMetaData {
+            finish_votes: vec![vec![first, second], vec![second, first]],
+            placements: vec![first, second],
+            rewards: vec![700, 300],
+            vote_weights: vec![600, 400],
+            voters: voters.clone(),
+            settled: 0,
+        };
+
+        assert_eq!(finish_meta_data.vote_of(&voters[1]), Some(1));
+        assert!(finish_meta_data.backed_winner(0));
+        assert!(!finish_meta_data.backed_winner(1));
+
+        finish_meta_data.settled = 0b01;
+        assert!(!finish_meta_data.all_settled());
+        finish_meta_data.settled = 0b11;
+        assert!(finish_meta_data.all_settled());
+    }
+
+    #[test]
+    fn test_verifier_stake_vote_weight() {
+        let mut stake = VerifierStake {
+            verifier: Pubkey::new_unique(),
+            amount: 0,
+            locked_until: 0,
+            active: true,
+            unsettled_votes: 0,
+        };
+        assert!(stake.vote_weight(100, 50).is_err());
+
+        stake.amount = 700;
+        stake.active = false;
+        assert!(stake.vote_weight(100, 50).is_err());
+
+        stake.active = true;
+        assert_eq!(stake.vote_weight(100, 50).unwrap(), 700);
+        assert_eq!(stake.locked_until, 150);
+    }
+
+    #[test]
+    fn test_tournament_settle_claim_deadline() {
+        let mut tournament = Tournament::deserialize(&mut &[0u8; Tournament::INIT_SPACE][..])
+            .expect("Failed to deserialize empty tournament");
+        tournament.settle(TournamentStatus::Finished, 100, 1000).unwrap();
+        assert_eq!(tournament.claim_deadline, 1100);
+
+        tournament.config.claim_period = Some(u64::MAX);
+        assert!(tournament.settle(TournamentStatus::Finished, 100, 1000).is_err());
+    }
+
+    #[test]
+    fn test_tournament_expired() {
+        let mut tournament = Tournament::deserialize(&mut &[0u8; Tournament::INIT_SPACE][..])
+            .expect("Failed to deserialize empty tournament");
+        tournament.config.expiration_time = 1000;
+        assert!(!tournament.expired(100, 999));
+        assert!(tournament.expired(100, 1000));
+
+        tournament.status = TournamentStatus::Started;
+        assert!(!tournament.expired(100, 1099));
+        assert!(tournament.expired(100, 1100));
+        tournament.config.claim_period = Some(500);
+        assert!(!tournament.expired(100, 1499));
+
+        tournament.status = TournamentStatus::Canceled;
+        assert!(!tournament.expired(100, u64::MAX));
+    }
+
+    #[test]
+    fn test_tournament_escrow_ledger() {
+        // An all-zero account is a valid empty tournament
+        let mut tournament = Tournament::deserialize(&mut &[0u8; Tournament::INIT_SPACE][..])
+            .expect("Failed to deserialize empty tournament");
+        tournament.record_sponsorship(1000);
+        tournament.record_entry_fee(300);
+        assert_eq!(tournament.escrow_balance().unwrap(), 1300);
+
+        tournament.record_payout(Payout::OrganizerFee, 130).unwrap();
+        tournament.record_payout(Payout::Reward, 1000).unwrap();
+        assert!(tournament.record_payout(Payout::Refund, 171).is_err());
+        tournament.record_payout(Payout::Unclaimed, 170).unwrap();
+        assert_eq!(tournament.escrow_balance().unwrap(), 0);
+
+        tournament.refunds_paid = 1;
+        assert!(tournament.escrow_balance().is_err());
+    }
 }
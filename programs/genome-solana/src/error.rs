@@ -46,4 +46,78 @@ pub(crate) enum GenomeError {
     NoCompletedTeams,
     #[msg("Insufficient funds")]
     InsufficientFunds,
+    #[msg("Invalid prize distribution")]
+    InvalidPrizeDistribution,
+    #[msg("Invalid placements")]
+    InvalidPlacements,
+    #[msg("Tournament has not expired yet")]
+    NotExpired,
+    #[msg("Max teams exceeded")]
+    MaxTeamsExceeded,
+    #[msg("Not enough completed teams to start the tournament")]
+    NotEnoughTeams,
+    #[msg("Invalid invite expiration time")]
+    InvalidInviteExpiration,
+    #[msg("Invite has expired")]
+    InviteExpired,
+    #[msg("Invite has not expired yet")]
+    InviteNotExpired,
+    #[msg("Participant is not in the tournament allowlist")]
+    InvalidAllowlistProof,
+    #[msg("Invalid tournament format")]
+    InvalidFormat,
+    #[msg("Invalid team")]
+    InvalidTeam,
+    #[msg("Invalid match")]
+    InvalidMatch,
+    #[msg("Bracket is not finished yet")]
+    BracketNotFinished,
+    #[msg("Invalid tiebreakers")]
+    InvalidTiebreakers,
+    #[msg("League is not finished yet")]
+    LeagueNotFinished,
+    #[msg("Invalid tournament metadata")]
+    InvalidMetadata,
+    #[msg("Tournament already has registrations")]
+    RegistrationsExist,
+    #[msg("Asset mint, sponsor and sponsor pool can't be changed")]
+    ImmutableConfig,
+    #[msg("Invalid organizer cancel penalty")]
+    InvalidPenalty,
+    #[msg("Some refunds or rewards are not claimed yet")]
+    ClaimsPending,
+    #[msg("Claim period is over")]
+    ClaimPeriodOver,
+    #[msg("Claim period is not over yet")]
+    ClaimPeriodNotOver,
+    #[msg("Invalid claim period")]
+    InvalidClaimPeriod,
+    #[msg("Max bonus pools exceeded")]
+    MaxBonusPoolsExceeded,
+    #[msg("Invalid bonus pool")]
+    InvalidBonusPool,
+    #[msg("Bonus pools must be closed first")]
+    BonusPoolsOpen,
+    #[msg("Arithmetic overflow")]
+    MathOverflow,
+    #[msg("Invalid stake amount")]
+    InvalidStake,
+    #[msg("Verifier has no stake")]
+    NoStake,
+    #[msg("Stake is locked")]
+    StakeLocked,
+    #[msg("Invalid stake lock period")]
+    InvalidStakeLockPeriod,
+    #[msg("Invalid verifier slash rate")]
+    InvalidSlashRate,
+    #[msg("Verifier didn't vote on the tournament finish")]
+    VoteNotFound,
+    #[msg("Verifier already settled")]
+    AlreadySettled,
+    #[msg("Some verifiers are not settled yet")]
+    VerifiersNotSettled,
+    #[msg("Verifier has unsettled finish votes")]
+    UnsettledVotes,
+    #[msg("Invalid remaining accounts")]
+    InvalidRemainingAccounts,
 }
@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+
+use crate::error::GenomeError;
+
+/// Denominator of every rate in the program: fees, prize shares, penalties and the
+/// consensus rate are all given in basis points.
+pub(crate) const BPS: u64 = 10_000;
+
+/// `amount * bps / BPS` rounded down, computed without intermediate overflow.
+pub(crate) fn bps_of(amount: u64, bps: u64) -> Result<u64> {
+    let value = amount as u128 * bps as u128 / BPS as u128;
+    u64::try_from(value).map_err(|_| GenomeError::MathOverflow.into())
+}
+
+/// Whether `part` out of `total` reaches `rate` basis points.
+pub(crate) fn reaches_rate(part: u64, total: u64, rate: u64) -> bool {
+    part as u128 * BPS as u128 >= total as u128 * rate as u128
+}
+
+/// Reward of every member of a team placed with `share` basis points of `pool`.
+pub(crate) fn member_reward(pool: u64, share: u16, team_size: u16) -> Result<u64> {
+    Ok(bps_of(pool, share as u64)? / team_size as u64)
+}
+
+/// Splits the reward pool of a finished tournament into the reward of a member of every
+/// placed team and the organizer's cut. Rewards are rounded down and the rounding remainders
+/// go to the organizer, so the pool is paid out in full.
+pub(crate) fn split_reward_pool(
+    pool: u64,
+    organizer_fee: u64,
+    prize_distribution: &[u16],
+    team_size: u16,
+) -> Result<(Vec<u64>, u64)> {
+    let prize_pool =
+        pool.checked_sub(bps_of(pool, organizer_fee)?).ok_or(GenomeError::MathOverflow)?;
+    let rewards = prize_distribution
+        .iter()
+        .map(|&share| member_reward(prize_pool, share, team_size))
+        .collect::<Result<Vec<u64>>>()?;
+
+    let paid = rewards
+        .iter()
+        .try_fold(0u64, |total, &reward| total.checked_add(reward.checked_mul(team_size as u64)?))
+        .ok_or(GenomeError::MathOverflow)?;
+    let organizer_cut = pool.checked_sub(paid).ok_or(GenomeError::MathOverflow)?;
+
+    Ok((rewards, organizer_cut))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_reward_pool_remainder_to_organizer() {
+        let (rewards, organizer_cut) = split_reward_pool(1001, 1000, &[7000, 3000], 3).unwrap();
+        // 901 for prizes: 630 / 3 = 210 and 270 / 3 = 90, the organizer gets 100 plus 1 of dust
+        assert_eq!(rewards, vec![210, 90]);
+        assert_eq!(organizer_cut, 101);
+        assert_eq!(rewards.iter().sum::<u64>() * 3 + organizer_cut, 1001);
+
+        let pool = u64::MAX / 2;
+        let (rewards, organizer_cut) = split_reward_pool(pool, 333, &[5000, 5000], 1).unwrap();
+        assert_eq!(rewards[0] + rewards[1] + organizer_cut, pool);
+        assert!(split_reward_pool(pool, BPS + 1, &[BPS as u16], 1).is_err());
+    }
+
+    #[test]
+    fn test_reaches_rate() {
+        assert!(reaches_rate(2, 3, 6600));
+        assert!(!reaches_rate(2, 3, 6700));
+        assert!(reaches_rate(u64::MAX, u64::MAX, BPS));
+    }
+}
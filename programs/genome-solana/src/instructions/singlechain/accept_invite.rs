@@ -0,0 +1,117 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use growable_bloom_filter::GrowableBloom as Bloom;
+
+use crate::{
+    data::{BloomFilter, Tournament, TournamentStatus},
+    error::GenomeError,
+    reward_pool::RewardPool,
+    team::Team,
+    utils::take_bloom_slot,
+    BLOOM, GENOME_ROOT, TEAM, TOURNAMENT,
+};
+
+pub fn handle_accept_invite(
+    ctx: Context<AcceptInvite>,
+    tournament_id: u32,
+    captain: Pubkey,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let tournament = &mut ctx.accounts.tournament;
+    let team = &mut ctx.accounts.team;
+    let bloom_filter = &mut ctx.accounts.bloom_filter;
+    let participant = ctx.accounts.participant.key();
+
+    require!(clock.unix_timestamp as u64 <= team.invite_expiration, GenomeError::InviteExpired);
+
+    let mut bloom: Bloom =
+        bincode::deserialize(&bloom_filter.data).expect("Error deserialize Bloom");
+    take_bloom_slot(&participant, &mut bloom, &mut bloom_filter.released)?;
+
+    let prepaid = team.accept_invite(&participant)?;
+    if !prepaid {
+        let reward_pool = RewardPool::new(
+            tournament,
+            ctx.bumps.tournament,
+            &ctx.accounts.mint,
+            ctx.accounts.reward_pool_ata.as_deref(),
+            &ctx.accounts.token_program,
+        )?;
+        let deposit = reward_pool.deposit(
+            ctx.accounts.participant_ata.as_ref().map(|ata| ata.to_account_info()),
+            ctx.accounts.participant.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+            tournament.config.entry_fee,
+        )?;
+        tournament.record_entry_fee(deposit);
+        team.record_deposit(&participant, deposit)?;
+    }
+
+    if team.completed {
+        tournament.team_count += 1;
+        tournament.entry_pool += team.deposits();
+    }
+    bloom_filter.data = bincode::serialize(&bloom).expect("Error serialize Bloom");
+
+    emit!(InviteAccepted {
+        tournament_id,
+        captain,
+        participant,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(tournament_id: u32, captain: Pubkey)]
+pub struct AcceptInvite<'info> {
+    #[account(mut)]
+    pub participant: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GENOME_ROOT, TOURNAMENT, tournament_id.to_le_bytes().as_ref()],
+        constraint = tournament.status == TournamentStatus::New @ GenomeError::InvalidStatus,
+        bump
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    #[account(
+        mut,
+        seeds = [GENOME_ROOT, TEAM, tournament_id.to_le_bytes().as_ref(), captain.as_ref()],
+        bump
+    )]
+    pub team: Account<'info, Team>,
+
+    #[account(address = tournament.config.asset_mint @ GenomeError::InvalidToken)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = participant,
+        associated_token::token_program = token_program,
+    )]
+    pub participant_ata: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = tournament,
+        associated_token::token_program = token_program,
+    )]
+    pub reward_pool_ata: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(mut, seeds = [GENOME_ROOT, BLOOM, tournament_id.to_le_bytes().as_ref()], bump)]
+    pub bloom_filter: Box<Account<'info, BloomFilter>>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[event]
+pub struct InviteAccepted {
+    pub tournament_id: u32,
+    pub captain: Pubkey,
+    pub participant: Pubkey,
+}
@@ -0,0 +1,134 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked},
+};
+
+use crate::{
+    bonus::BonusPool,
+    data::{TokenInfo, Tournament, TournamentStatus},
+    error::GenomeError,
+    BONUS, GENOME_ROOT, TOKEN, TOURNAMENT,
+};
+
+pub fn handle_add_bonus_pool(
+    ctx: Context<AddBonusPool>,
+    tournament_id: u32,
+    amount: u64,
+) -> Result<()> {
+    require!(
+        amount > 0 && amount >= ctx.accounts.token_info.min_sponsor_pool,
+        GenomeError::InvalidSponsorPool
+    );
+
+    let mint = ctx.accounts.bonus_mint.key();
+    let tournament = &mut ctx.accounts.tournament;
+    require!(mint != tournament.config.asset_mint, GenomeError::InvalidToken);
+    require!(
+        tournament.bonus_mints.len() < BonusPool::MAX_BONUS_POOLS,
+        GenomeError::MaxBonusPoolsExceeded
+    );
+    tournament.bonus_mints.push(mint);
+
+    let before = ctx.accounts.bonus_pool_ata.amount;
+    let accounts = TransferChecked {
+        from: ctx.accounts.sponsor_ata.to_account_info(),
+        to: ctx.accounts.bonus_pool_ata.to_account_info(),
+        mint: ctx.accounts.bonus_mint.to_account_info(),
+        authority: ctx.accounts.sponsor.to_account_info(),
+    };
+    let cpi = CpiContext::new(ctx.accounts.token_program.to_account_info(), accounts);
+    transfer_checked(cpi, amount, ctx.accounts.bonus_mint.decimals)?;
+
+    // Anyone can create the tournament's token account upfront and send tokens to it, so the
+    // bonus is only what arrived with this transfer after any transfer fee
+    ctx.accounts.bonus_pool_ata.reload()?;
+    let amount = ctx.accounts.bonus_pool_ata.amount - before;
+
+    let sponsor = ctx.accounts.sponsor.key();
+    ctx.accounts.bonus_pool.set_inner(BonusPool {
+        tournament_id,
+        sponsor,
+        mint,
+        amount,
+    });
+
+    emit!(BonusPoolAdded {
+        tournament_id,
+        sponsor,
+        mint,
+        amount
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(tournament_id: u32)]
+pub struct AddBonusPool<'info> {
+    #[account(mut)]
+    pub sponsor: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GENOME_ROOT, TOURNAMENT, tournament_id.to_le_bytes().as_ref()],
+        constraint = tournament.status == TournamentStatus::New @ GenomeError::InvalidStatus,
+        bump
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    #[account(
+        init,
+        payer = sponsor,
+        space = BonusPool::DISCRIMINATOR.len() + BonusPool::INIT_SPACE,
+        seeds = [GENOME_ROOT, BONUS, tournament_id.to_le_bytes().as_ref(), bonus_mint.key().as_ref()],
+        bump
+    )]
+    pub bonus_pool: Account<'info, BonusPool>,
+
+    /// Bonuses are paid in the same transfers as the reward, so the bonus mint has to live
+    /// under the asset mint's token program
+    #[account(
+        constraint = bonus_mint.to_account_info().owner == asset_mint.to_account_info().owner
+            @ GenomeError::InvalidToken
+    )]
+    pub bonus_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(address = tournament.config.asset_mint @ GenomeError::InvalidToken)]
+    pub asset_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        seeds = [GENOME_ROOT, TOKEN, bonus_mint.key().as_ref()],
+        bump,
+    )]
+    pub token_info: Account<'info, TokenInfo>,
+
+    #[account(
+        mut,
+        associated_token::mint = bonus_mint,
+        associated_token::authority = sponsor,
+        associated_token::token_program = token_program,
+    )]
+    pub sponsor_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = sponsor,
+        associated_token::mint = bonus_mint,
+        associated_token::authority = tournament,
+        associated_token::token_program = token_program,
+    )]
+    pub bonus_pool_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[event]
+pub struct BonusPoolAdded {
+    pub tournament_id: u32,
+    pub sponsor: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+}
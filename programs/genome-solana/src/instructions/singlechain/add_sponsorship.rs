@@ -0,0 +1,103 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    data::{Sponsorship, Tournament, TournamentStatus},
+    error::GenomeError,
+    reward_pool::RewardPool,
+    GENOME_ROOT, SPONSORSHIP, TOURNAMENT,
+};
+
+pub fn handle_add_sponsorship(
+    ctx: Context<AddSponsorship>,
+    tournament_id: u32,
+    amount: u64,
+) -> Result<()> {
+    require!(amount > 0, GenomeError::InvalidSponsorPool);
+
+    let reward_pool = RewardPool::new(
+        &ctx.accounts.tournament,
+        ctx.bumps.tournament,
+        &ctx.accounts.asset_mint,
+        ctx.accounts.reward_pool_ata.as_deref(),
+        &ctx.accounts.token_program,
+    )?;
+    let amount = reward_pool.deposit(
+        ctx.accounts.sponsor_ata.as_ref().map(|ata| ata.to_account_info()),
+        ctx.accounts.sponsor.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        amount,
+    )?;
+
+    let sponsor = ctx.accounts.sponsor.key();
+    let sponsorship = &mut ctx.accounts.sponsorship;
+    if sponsorship.sponsor == Pubkey::default() {
+        ctx.accounts.tournament.sponsorships += 1;
+    }
+    sponsorship.sponsor = sponsor;
+    sponsorship.amount += amount;
+    ctx.accounts.tournament.record_sponsorship(amount);
+
+    emit!(SponsorshipAdded {
+        tournament_id,
+        sponsor,
+        amount,
+        sponsor_pool: ctx.accounts.tournament.sponsor_pool,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(tournament_id: u32)]
+pub struct AddSponsorship<'info> {
+    #[account(mut)]
+    pub sponsor: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GENOME_ROOT, TOURNAMENT, tournament_id.to_le_bytes().as_ref()],
+        constraint = tournament.status == TournamentStatus::New @ GenomeError::InvalidStatus,
+        bump
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    #[account(
+        init_if_needed,
+        payer = sponsor,
+        space = Sponsorship::DISCRIMINATOR.len() + Sponsorship::INIT_SPACE,
+        seeds = [GENOME_ROOT, SPONSORSHIP, tournament_id.to_le_bytes().as_ref(), sponsor.key().as_ref()],
+        bump
+    )]
+    pub sponsorship: Account<'info, Sponsorship>,
+
+    #[account(constraint = asset_mint.key() == tournament.config.asset_mint @ GenomeError::InvalidToken)]
+    pub asset_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = asset_mint,
+        associated_token::authority = sponsor,
+        associated_token::token_program = token_program,
+    )]
+    pub sponsor_ata: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        mut,
+        associated_token::mint = asset_mint,
+        associated_token::authority = tournament,
+        associated_token::token_program = token_program,
+    )]
+    pub reward_pool_ata: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[event]
+pub struct SponsorshipAdded {
+    pub tournament_id: u32,
+    pub sponsor: Pubkey,
+    pub amount: u64,
+    pub sponsor_pool: u64,
+}
@@ -1,7 +1,11 @@
 use crate::{
-    data::{Consensus, GenomeSingleConfig, RoleInfo, RoleList, Tournament, TournamentStatus},
+    data::{
+        Consensus, GenomeSingleConfig, RoleInfo, RoleList, Tournament, TournamentStatus,
+        VerifierStake,
+    },
     error::GenomeError,
-    Role, CONSENSUS, GENOME_ROOT, ROLE, SINGLE_CONFIG, TOURNAMENT,
+    fees::reaches_rate,
+    Role, CONSENSUS, GENOME_ROOT, ROLE, SINGLE_CONFIG, STAKE, TOURNAMENT,
 };
 use anchor_lang::prelude::*;
 
@@ -22,15 +26,16 @@ pub fn handle_cancel_tournament(ctx: Context<CancelTournament>, tournament_id: u
 
     require!((consensus.cancel_votes >> verifier_index) & 1 == 0, GenomeError::AlreadyVoted);
 
+    let now = Clock::get()?.unix_timestamp as u64;
+    let weight = ctx.accounts.stake.vote_weight(now, config.stake_lock_period)?;
+
     consensus.cancel_votes |= 1 << verifier_index;
+    consensus.cancel_weight += weight;
     role_info_ver.claim += config.verifier_fee;
 
-    let votes = consensus.cancel_votes.count_ones() as u64;
-    let total = ctx.accounts.verifier_list.accounts.len() as u64;
-
-    if votes * 10000 >= total * config.consensus_rate {
+    if reaches_rate(consensus.cancel_weight, config.total_stake, config.consensus_rate) {
         ctx.accounts.role_info_org.claim += config.platform_fee;
-        tournament.status = TournamentStatus::Canceled;
+        tournament.settle(TournamentStatus::Canceled, config.claim_period, now)?;
         emit!(TournamentCanceled { tournament_id });
     }
 
@@ -50,6 +55,9 @@ pub struct CancelTournament<'info> {
     )]
     pub role_info_ver: Account<'info, RoleInfo>,
 
+    #[account(mut, seeds = [GENOME_ROOT, STAKE, verifier.key().as_ref()], bump)]
+    pub stake: Account<'info, VerifierStake>,
+
     #[account(
         seeds = [GENOME_ROOT, ROLE, Role::Verifier.to_seed()],
         bump
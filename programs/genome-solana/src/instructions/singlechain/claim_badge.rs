@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{associated_token::AssociatedToken, token_2022::Token2022};
+
+use crate::{
+    data::{Tournament, TournamentStatus},
+    error::GenomeError,
+    soulbound::SoulboundMint,
+    team::Team,
+    BADGE, GENOME_ROOT, TEAM, TOURNAMENT,
+};
+
+/// Mints the tournament's participation badge to a member of a completed team once the
+/// tournament has started.
+pub fn handle_claim_badge(
+    ctx: Context<ClaimBadge>,
+    tournament_id: u32,
+    _captain: Pubkey,
+) -> Result<()> {
+    let participant = ctx.accounts.participant.key();
+    ctx.accounts.team.claim_badge(&participant)?;
+
+    let badge = SoulboundMint {
+        payer: ctx.accounts.participant.to_account_info(),
+        authority: ctx.accounts.badge_authority.to_account_info(),
+        mint: ctx.accounts.badge_mint.to_account_info(),
+        token_program: ctx.accounts.token_2022_program.to_account_info(),
+        associated_token_program: ctx.accounts.associated_token_program.to_account_info(),
+        system_program: ctx.accounts.system_program.to_account_info(),
+        authority_bump: ctx.bumps.badge_authority,
+        mint_bump: ctx.bumps.badge_mint,
+    };
+    badge.award_badge(
+        tournament_id,
+        ctx.accounts.participant.to_account_info(),
+        ctx.accounts.participant_badge_ata.to_account_info(),
+    )?;
+
+    emit!(BadgeClaimed {
+        tournament_id,
+        participant
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(tournament_id: u32, captain: Pubkey)]
+pub struct ClaimBadge<'info> {
+    #[account(mut)]
+    pub participant: Signer<'info>,
+
+    #[account(
+        seeds = [GENOME_ROOT, TOURNAMENT, tournament_id.to_le_bytes().as_ref()],
+        constraint = tournament.status == TournamentStatus::Started
+            || tournament.status == TournamentStatus::Finished @ GenomeError::InvalidStatus,
+        bump
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    #[account(
+        mut,
+        seeds = [GENOME_ROOT, TEAM, tournament_id.to_le_bytes().as_ref(), captain.as_ref()],
+        bump,
+    )]
+    pub team: Account<'info, Team>,
+
+    /// CHECK: PDA used as the badge mint and metadata authority
+    #[account(seeds = [GENOME_ROOT, BADGE], bump)]
+    pub badge_authority: UncheckedAccount<'info>,
+
+    /// CHECK: the tournament's badge mint, created on the first claim
+    #[account(
+        mut,
+        seeds = [GENOME_ROOT, BADGE, tournament_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub badge_mint: UncheckedAccount<'info>,
+
+    /// CHECK: participant's badge token account, its address is checked by the associated token program
+    #[account(mut)]
+    pub participant_badge_ata: UncheckedAccount<'info>,
+
+    pub token_2022_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct BadgeClaimed {
+    pub tournament_id: u32,
+    pub participant: Pubkey,
+}
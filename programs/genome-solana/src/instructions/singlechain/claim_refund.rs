@@ -1,11 +1,10 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token_interface::{
-    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
-};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 use crate::{
-    data::{Tournament, TournamentStatus},
+    data::{Payout, Tournament, TournamentStatus},
     error::GenomeError,
+    reward_pool::RewardPool,
     team::Team,
     GENOME_ROOT, TEAM, TOURNAMENT,
 };
@@ -15,7 +14,9 @@ pub fn handle_claim_refund(
     tournament_id: u32,
     _captain: Pubkey,
 ) -> Result<()> {
-    let tournament = &ctx.accounts.tournament;
+    let tournament = &mut ctx.accounts.tournament;
+    let now = Clock::get()?.unix_timestamp as u64;
+    require!(tournament.claims_open(now), GenomeError::ClaimPeriodOver);
     let team = &mut ctx.accounts.team;
 
     if tournament.status == TournamentStatus::New
@@ -25,34 +26,23 @@ pub fn handle_claim_refund(
     }
 
     let participant = ctx.accounts.participant.key();
-    let count = team.refund_participant(&participant)?;
-
-    let amount_to_refund = tournament.config.entry_fee * count as u64;
-
-    let tournament_seeds = &[
-        GENOME_ROOT,
-        TOURNAMENT,
-        &tournament.id.to_le_bytes(),
-        &[ctx.bumps.tournament],
-    ];
-    let signer = &[&tournament_seeds[..]];
-
-    let transfer_accounts = TransferChecked {
-        from: ctx.accounts.reward_pool_ata.to_account_info(),
-        to: ctx.accounts.participant_ata.to_account_info(),
-        mint: ctx.accounts.asset_mint.to_account_info(),
-        authority: ctx.accounts.tournament.to_account_info(),
-    };
-    let cpi = CpiContext::new_with_signer(
-        ctx.accounts.token_program.to_account_info(),
-        transfer_accounts,
-        signer,
-    );
-    transfer_checked(cpi, amount_to_refund, ctx.accounts.asset_mint.decimals)?;
+    let amount_to_refund = team.refund_participant(&participant)?;
+
+    let reward_pool = RewardPool::new(
+        tournament,
+        ctx.bumps.tournament,
+        &ctx.accounts.asset_mint,
+        ctx.accounts.reward_pool_ata.as_deref(),
+        &ctx.accounts.token_program,
+    )?;
+    let refund_to = reward_pool
+        .recipient(&ctx.accounts.participant_ata, ctx.accounts.participant.to_account_info())?;
+    tournament.record_payout(Payout::Refund, amount_to_refund)?;
+    reward_pool.pay(refund_to, amount_to_refund)?;
 
     emit!(RefundClaimed {
         tournament_id,
-        participant: participant,
+        participant,
         amount: amount_to_refund,
     });
 
@@ -87,14 +77,14 @@ pub struct ClaimRefund<'info> {
         associated_token::mint = asset_mint,
         associated_token::authority = participant,
     )]
-    pub participant_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub participant_ata: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
 
     #[account(
         mut,
         associated_token::mint = asset_mint,
         associated_token::authority = tournament
     )]
-    pub reward_pool_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub reward_pool_ata: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
 
     pub token_program: Interface<'info, TokenInterface>,
 }
@@ -1,25 +1,38 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token_interface::{
-    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_2022::Token2022,
+    token_interface::{Mint, TokenAccount, TokenInterface},
 };
 
 use crate::{
-    data::{FinishMetaData, Tournament, TournamentStatus},
+    bonus::{load_bonus_payouts, pay_bonuses},
+    data::{FinishMetaData, Payout, Tournament, TournamentStatus},
     error::GenomeError,
+    reward_pool::RewardPool,
+    soulbound::SoulboundMint,
     team::Team,
-    FINISH, GENOME_ROOT, TEAM, TOURNAMENT,
+    FINISH, GENOME_ROOT, TEAM, TOURNAMENT, TROPHY,
 };
 
-pub fn handle_claim_reward(
-    ctx: Context<ClaimReward>,
+/// Pays the participant's share of the reward pool and of every bonus pool and mints them
+/// the team's trophy. `remaining_accounts` holds the bonus pool, its mint and its token
+/// account for every entry of `Tournament::bonus_mints`, followed by the participant's token
+/// account for each of them.
+pub fn handle_claim_reward<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ClaimReward<'info>>,
     tournament_id: u32,
     captain: Pubkey,
 ) -> Result<()> {
-    require!(ctx.accounts.finish_meta_data.captain_winner == captain, GenomeError::NotWinner);
+    let finish_meta_data = &ctx.accounts.finish_meta_data;
+    let place = finish_meta_data.place_of(&captain).ok_or(GenomeError::NotWinner)?;
+    let reward = finish_meta_data.rewards[place];
 
+    ctx.accounts.tournament.record_payout(Payout::Reward, reward)?;
     let tournament = &ctx.accounts.tournament;
+    let now = Clock::get()?.unix_timestamp as u64;
+    require!(tournament.claims_open(now), GenomeError::ClaimPeriodOver);
     let team = &mut ctx.accounts.team;
-    let finish_meta_data = &ctx.accounts.finish_meta_data;
 
     let participant = ctx.accounts.participant.key();
     team.reward_participant(&participant)?;
@@ -32,23 +45,56 @@ pub fn handle_claim_reward(
     ];
     let signer = &[&tournament_seeds[..]];
 
-    let transfer_accounts = TransferChecked {
-        from: ctx.accounts.reward_pool_ata.to_account_info(),
-        to: ctx.accounts.participant_ata.to_account_info(),
-        mint: ctx.accounts.asset_mint.to_account_info(),
-        authority: ctx.accounts.tournament.to_account_info(),
-    };
-    let cpi = CpiContext::new_with_signer(
+    let reward_pool = RewardPool::new(
+        tournament,
+        ctx.bumps.tournament,
+        &ctx.accounts.asset_mint,
+        ctx.accounts.reward_pool_ata.as_deref(),
+        &ctx.accounts.token_program,
+    )?;
+    let reward_to = reward_pool
+        .recipient(&ctx.accounts.participant_ata, ctx.accounts.participant.to_account_info())?;
+    reward_pool.pay(reward_to, reward)?;
+
+    let pools_count = 3 * tournament.bonus_mints.len();
+    require!(
+        ctx.remaining_accounts.len() == pools_count + tournament.bonus_mints.len(),
+        GenomeError::InvalidBonusPool
+    );
+    let (pool_accounts, recipients) = ctx.remaining_accounts.split_at(pools_count);
+    let payouts = load_bonus_payouts(tournament, pool_accounts)?;
+    pay_bonuses(
+        &payouts,
+        recipients,
+        &participant,
+        tournament.config.prize_distribution[place],
+        tournament,
         ctx.accounts.token_program.to_account_info(),
-        transfer_accounts,
         signer,
-    );
-    transfer_checked(cpi, finish_meta_data.reward, ctx.accounts.asset_mint.decimals)?;
+    )?;
+
+    let trophy = SoulboundMint {
+        payer: ctx.accounts.participant.to_account_info(),
+        authority: ctx.accounts.trophy_authority.to_account_info(),
+        mint: ctx.accounts.trophy_mint.to_account_info(),
+        token_program: ctx.accounts.token_2022_program.to_account_info(),
+        associated_token_program: ctx.accounts.associated_token_program.to_account_info(),
+        system_program: ctx.accounts.system_program.to_account_info(),
+        authority_bump: ctx.bumps.trophy_authority,
+        mint_bump: ctx.bumps.trophy_mint,
+    };
+    trophy.award_trophy(
+        tournament_id,
+        place,
+        &captain,
+        ctx.accounts.participant.to_account_info(),
+        ctx.accounts.participant_trophy_ata.to_account_info(),
+    )?;
 
     emit!(RewardClaimed {
         tournament_id,
         participant,
-        amount: finish_meta_data.reward
+        amount: reward
     });
 
     Ok(())
@@ -90,16 +136,35 @@ pub struct ClaimReward<'info> {
         associated_token::mint = tournament.config.asset_mint,
         associated_token::authority = participant,
     )]
-    pub participant_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub participant_ata: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
 
     #[account(
         mut,
         associated_token::mint = tournament.config.asset_mint,
         associated_token::authority = tournament,
     )]
-    pub reward_pool_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub reward_pool_ata: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// CHECK: PDA used as the trophy mint and metadata authority
+    #[account(seeds = [GENOME_ROOT, TROPHY], bump)]
+    pub trophy_authority: UncheckedAccount<'info>,
+
+    /// CHECK: the team's trophy mint, created on the first claim of the team
+    #[account(
+        mut,
+        seeds = [GENOME_ROOT, TROPHY, tournament_id.to_le_bytes().as_ref(), captain.as_ref()],
+        bump
+    )]
+    pub trophy_mint: UncheckedAccount<'info>,
+
+    /// CHECK: participant's trophy token account, its address is checked by the associated token program
+    #[account(mut)]
+    pub participant_trophy_ata: UncheckedAccount<'info>,
 
     pub token_program: Interface<'info, TokenInterface>,
+    pub token_2022_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
 }
 
 #[event]
@@ -1,48 +1,41 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token_interface::{
-    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
-};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 use crate::{
-    data::{Tournament, TournamentStatus},
+    data::{Payout, Sponsorship, Tournament, TournamentStatus},
     error::GenomeError,
-    GENOME_ROOT, TOURNAMENT,
+    reward_pool::RewardPool,
+    GENOME_ROOT, SPONSORSHIP, TOURNAMENT,
 };
 
 pub fn handle_claim_sponsor_funds(
     ctx: Context<ClaimSponsorRefund>,
     tournament_id: u32,
 ) -> Result<()> {
-    require!(
-        ctx.accounts.reward_pool_ata.amount >= ctx.accounts.tournament.config.sponsor_pool,
-        GenomeError::InsufficientFunds
-    );
+    let now = Clock::get()?.unix_timestamp as u64;
+    require!(ctx.accounts.tournament.claims_open(now), GenomeError::ClaimPeriodOver);
 
-    let tournament_seeds = &[
-        GENOME_ROOT,
-        TOURNAMENT,
-        &tournament_id.to_le_bytes(),
-        &[ctx.bumps.tournament],
-    ];
-    let signer = &[&tournament_seeds[..]];
+    require!(!ctx.accounts.sponsorship.refunded, GenomeError::AlreadyClaimed);
+    let amount = ctx.accounts.sponsorship.amount;
+    let reward_pool = RewardPool::new(
+        &ctx.accounts.tournament,
+        ctx.bumps.tournament,
+        &ctx.accounts.asset_mint,
+        ctx.accounts.reward_pool_ata.as_deref(),
+        &ctx.accounts.token_program,
+    )?;
+    ctx.accounts.tournament.record_payout(Payout::Refund, amount)?;
+    ctx.accounts.sponsorship.refunded = true;
 
-    let transfer_accounts = TransferChecked {
-        from: ctx.accounts.reward_pool_ata.to_account_info(),
-        to: ctx.accounts.sponsor_ata.to_account_info(),
-        mint: ctx.accounts.asset_mint.to_account_info(),
-        authority: ctx.accounts.tournament.to_account_info(),
-    };
-    let cpi_ctx = CpiContext::new_with_signer(
-        ctx.accounts.token_program.to_account_info(),
-        transfer_accounts,
-        signer,
-    );
+    let refund_to =
+        reward_pool.recipient(&ctx.accounts.sponsor_ata, ctx.accounts.sponsor.to_account_info())?;
+    reward_pool.pay(refund_to, amount)?;
 
-    transfer_checked(
-        cpi_ctx,
-        ctx.accounts.tournament.config.sponsor_pool,
-        ctx.accounts.asset_mint.decimals,
-    )?;
+    emit!(SponsorRefunded {
+        tournament_id,
+        sponsor: ctx.accounts.sponsor.key(),
+        amount
+    });
 
     Ok(())
 }
@@ -50,10 +43,7 @@ pub fn handle_claim_sponsor_funds(
 #[derive(Accounts)]
 #[instruction(tournament_id: u32)]
 pub struct ClaimSponsorRefund<'info> {
-    #[account(
-        mut,
-        constraint = sponsor.key() == tournament.config.sponsor @ GenomeError::NotAllowed)
-    ]
+    #[account(mut)]
     pub sponsor: Signer<'info>,
 
     #[account(
@@ -63,6 +53,13 @@ pub struct ClaimSponsorRefund<'info> {
         bump)]
     pub tournament: Account<'info, Tournament>,
 
+    #[account(
+        mut,
+        seeds = [GENOME_ROOT, SPONSORSHIP, tournament_id.to_le_bytes().as_ref(), sponsor.key().as_ref()],
+        bump
+    )]
+    pub sponsorship: Account<'info, Sponsorship>,
+
     #[account(constraint = asset_mint.key() == tournament.config.asset_mint @ GenomeError::InvalidNome)]
     pub asset_mint: InterfaceAccount<'info, Mint>,
 
@@ -71,14 +68,21 @@ pub struct ClaimSponsorRefund<'info> {
         associated_token::mint = asset_mint,
         associated_token::authority = sponsor,
     )]
-    pub sponsor_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub sponsor_ata: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
 
     #[account(
         mut,
         associated_token::mint = asset_mint,
         associated_token::authority = tournament,
     )]
-    pub reward_pool_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub reward_pool_ata: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
 
     pub token_program: Interface<'info, TokenInterface>,
 }
+
+#[event]
+pub struct SponsorRefunded {
+    pub tournament_id: u32,
+    pub sponsor: Pubkey,
+    pub amount: u64,
+}
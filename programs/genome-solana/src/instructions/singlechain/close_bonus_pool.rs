@@ -0,0 +1,122 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    close_account, transfer_checked, CloseAccount, Mint, TokenAccount, TokenInterface,
+    TransferChecked,
+};
+
+use crate::{
+    bonus::BonusPool,
+    data::{Tournament, TournamentStatus},
+    error::GenomeError,
+    BONUS, GENOME_ROOT, TOURNAMENT,
+};
+
+/// Returns whatever is left in a bonus pool to its sponsor: the whole bonus when the
+/// tournament is canceled, the unpaid part once a finished tournament's claims are settled.
+pub fn handle_close_bonus_pool(ctx: Context<CloseBonusPool>, tournament_id: u32) -> Result<()> {
+    let tournament = &mut ctx.accounts.tournament;
+    if tournament.status == TournamentStatus::Finished {
+        let now = Clock::get()?.unix_timestamp as u64;
+        require!(
+            tournament.registered_teams == 0 || !tournament.claims_open(now),
+            GenomeError::ClaimsPending
+        );
+    }
+
+    let mint = ctx.accounts.bonus_mint.key();
+    tournament.bonus_mints.retain(|bonus_mint| *bonus_mint != mint);
+
+    let tournament_seeds = &[
+        GENOME_ROOT,
+        TOURNAMENT,
+        &tournament_id.to_le_bytes(),
+        &[ctx.bumps.tournament],
+    ];
+    let signer = &[&tournament_seeds[..]];
+
+    let amount = ctx.accounts.bonus_pool_ata.amount;
+    if amount > 0 {
+        let accounts = TransferChecked {
+            from: ctx.accounts.bonus_pool_ata.to_account_info(),
+            to: ctx.accounts.sponsor_ata.to_account_info(),
+            mint: ctx.accounts.bonus_mint.to_account_info(),
+            authority: tournament.to_account_info(),
+        };
+        let cpi = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            accounts,
+            signer,
+        );
+        transfer_checked(cpi, amount, ctx.accounts.bonus_mint.decimals)?;
+    }
+
+    let accounts = CloseAccount {
+        account: ctx.accounts.bonus_pool_ata.to_account_info(),
+        destination: ctx.accounts.sponsor.to_account_info(),
+        authority: tournament.to_account_info(),
+    };
+    let cpi =
+        CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), accounts, signer);
+    close_account(cpi)?;
+
+    emit!(BonusPoolClosed {
+        tournament_id,
+        mint,
+        refund: amount
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(tournament_id: u32)]
+pub struct CloseBonusPool<'info> {
+    pub caller: Signer<'info>,
+
+    #[account(mut, address = bonus_pool.sponsor @ GenomeError::NotAllowed)]
+    pub sponsor: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [GENOME_ROOT, TOURNAMENT, tournament_id.to_le_bytes().as_ref()],
+        constraint = tournament.status == TournamentStatus::Finished
+            || tournament.status == TournamentStatus::Canceled @ GenomeError::InvalidStatus,
+        bump
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    #[account(
+        mut,
+        close = sponsor,
+        seeds = [GENOME_ROOT, BONUS, tournament_id.to_le_bytes().as_ref(), bonus_mint.key().as_ref()],
+        bump
+    )]
+    pub bonus_pool: Account<'info, BonusPool>,
+
+    pub bonus_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = bonus_mint,
+        associated_token::authority = sponsor,
+        associated_token::token_program = token_program,
+    )]
+    pub sponsor_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = bonus_mint,
+        associated_token::authority = tournament,
+        associated_token::token_program = token_program,
+    )]
+    pub bonus_pool_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[event]
+pub struct BonusPoolClosed {
+    pub tournament_id: u32,
+    pub mint: Pubkey,
+    pub refund: u64,
+}
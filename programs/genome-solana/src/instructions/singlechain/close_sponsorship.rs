@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    data::{Sponsorship, Tournament, TournamentStatus},
+    error::GenomeError,
+    GENOME_ROOT, SPONSORSHIP, TOURNAMENT,
+};
+
+/// Closes a sponsorship that has nothing left to claim and returns the rent to the sponsor:
+/// any sponsorship of a finished tournament, or one of a canceled tournament once it has been
+/// refunded or the claim period is over.
+pub fn handle_close_sponsorship(
+    ctx: Context<CloseSponsorship>,
+    tournament_id: u32,
+    sponsor: Pubkey,
+) -> Result<()> {
+    let tournament = &mut ctx.accounts.tournament;
+    let now = Clock::get()?.unix_timestamp as u64;
+    require!(
+        tournament.status == TournamentStatus::Finished
+            || ctx.accounts.sponsorship.refunded
+            || !tournament.claims_open(now),
+        GenomeError::ClaimsPending
+    );
+
+    tournament.sponsorships -= 1;
+
+    emit!(SponsorshipClosed {
+        tournament_id,
+        sponsor
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(tournament_id: u32, sponsor: Pubkey)]
+pub struct CloseSponsorship<'info> {
+    #[account(mut, address = sponsorship.sponsor @ GenomeError::NotAllowed)]
+    pub sponsor: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [GENOME_ROOT, TOURNAMENT, tournament_id.to_le_bytes().as_ref()],
+        constraint = tournament.status == TournamentStatus::Finished
+            || tournament.status == TournamentStatus::Canceled @ GenomeError::InvalidStatus,
+        bump
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    #[account(
+        mut,
+        close = sponsor,
+        seeds = [GENOME_ROOT, SPONSORSHIP, tournament_id.to_le_bytes().as_ref(), sponsor.key().as_ref()],
+        bump
+    )]
+    pub sponsorship: Account<'info, Sponsorship>,
+}
+
+#[event]
+pub struct SponsorshipClosed {
+    pub tournament_id: u32,
+    pub sponsor: Pubkey,
+}
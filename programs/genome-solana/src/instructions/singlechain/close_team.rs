@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    data::{FinishMetaData, Tournament, TournamentStatus},
+    error::GenomeError,
+    team::Team,
+    FINISH, GENOME_ROOT, TEAM, TOURNAMENT,
+};
+
+/// Closes the team once everything it is owed has been claimed or the claim period is over,
+/// and returns the rent to the captain. Canceled tournaments and incomplete teams are owed
+/// refunds, winning teams of a finished tournament are owed rewards.
+pub fn handle_close_team(
+    ctx: Context<CloseTeam>,
+    tournament_id: u32,
+    captain: Pubkey,
+) -> Result<()> {
+    let tournament = &mut ctx.accounts.tournament;
+    let team = &ctx.accounts.team;
+
+    let settled = if tournament.status == TournamentStatus::Canceled || !team.completed {
+        team.refunds_claimed()
+    } else {
+        match ctx.accounts.finish_meta_data.reward_of(&captain) {
+            Some(reward) if reward > 0 => team.rewards_claimed(),
+            _ => true,
+        }
+    };
+    let now = Clock::get()?.unix_timestamp as u64;
+    require!(settled || !tournament.claims_open(now), GenomeError::ClaimsPending);
+
+    tournament.registered_teams -= 1;
+
+    emit!(TeamClosed {
+        tournament_id,
+        captain
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(tournament_id: u32, captain: Pubkey)]
+pub struct CloseTeam<'info> {
+    #[account(mut, address = team.captain @ GenomeError::NotAllowed)]
+    pub captain: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [GENOME_ROOT, TOURNAMENT, tournament_id.to_le_bytes().as_ref()],
+        constraint = tournament.status == TournamentStatus::Finished
+            || tournament.status == TournamentStatus::Canceled @ GenomeError::InvalidStatus,
+        bump
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    #[account(
+        mut,
+        close = captain,
+        seeds = [GENOME_ROOT, TEAM, tournament_id.to_le_bytes().as_ref(), captain.key().as_ref()],
+        bump
+    )]
+    pub team: Account<'info, Team>,
+
+    #[account(seeds = [GENOME_ROOT, FINISH, tournament_id.to_le_bytes().as_ref()], bump)]
+    pub finish_meta_data: Account<'info, FinishMetaData>,
+}
+
+#[event]
+pub struct TeamClosed {
+    pub tournament_id: u32,
+    pub captain: Pubkey,
+}
@@ -0,0 +1,193 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    close_account, CloseAccount, Mint, TokenAccount, TokenInterface,
+};
+
+use crate::{
+    bracket::Bracket,
+    data::{
+        BloomFilter, Consensus, FinishMetaData, Tournament, TournamentFormat, TournamentMetadata,
+        TournamentStatus,
+    },
+    error::GenomeError,
+    league::League,
+    reward_pool::RewardPool,
+    BLOOM, BRACKET, CONSENSUS, FINISH, GENOME_ROOT, LEAGUE, METADATA, TOURNAMENT,
+};
+
+/// Closes every per-tournament account once all teams are closed. Rent goes back to the
+/// organizer, except for the bracket or the league, which go back to whoever created them.
+/// The rounding leftovers of a finished tournament's reward pool go to the organizer, while
+/// anything left after the claim period has to be swept with `sweep_unclaimed` first, and
+/// every finish vote has to be settled with `settle_verifier`.
+pub fn handle_close_tournament(ctx: Context<CloseTournament>, tournament_id: u32) -> Result<()> {
+    let tournament = &ctx.accounts.tournament;
+    let finished = tournament.status == TournamentStatus::Finished;
+    require!(ctx.accounts.finish_meta_data.all_settled(), GenomeError::VerifiersNotSettled);
+
+    let format = &tournament.config.format;
+    // A tournament that expired after it started may or may not have its schedule created.
+    let bracket = *format == TournamentFormat::SingleElimination;
+    let league = *format == TournamentFormat::League;
+    require!(
+        (ctx.accounts.bracket.is_some() || !(finished && bracket))
+            && (ctx.accounts.bracket.is_none() || bracket)
+            && (ctx.accounts.league.is_some() || !(finished && league))
+            && (ctx.accounts.league.is_none() || league),
+        GenomeError::InvalidFormat
+    );
+    if let Some(bracket) = &ctx.accounts.bracket {
+        let payer = schedule_payer(&ctx.accounts.schedule_payer, bracket.payer)?;
+        bracket.close(payer)?;
+    }
+    if let Some(league) = &ctx.accounts.league {
+        let payer = schedule_payer(&ctx.accounts.schedule_payer, league.payer)?;
+        league.close(payer)?;
+    }
+
+    let tournament_seeds = &[
+        GENOME_ROOT,
+        TOURNAMENT,
+        &tournament_id.to_le_bytes(),
+        &[ctx.bumps.tournament],
+    ];
+    let signer = &[&tournament_seeds[..]];
+
+    let reward_pool = RewardPool::new(
+        tournament,
+        ctx.bumps.tournament,
+        &ctx.accounts.asset_mint,
+        ctx.accounts.reward_pool_ata.as_deref(),
+        &ctx.accounts.token_program,
+    )?;
+    let leftover = reward_pool.balance()?;
+    if leftover > 0 {
+        let now = Clock::get()?.unix_timestamp as u64;
+        require!(finished && tournament.claims_open(now), GenomeError::ClaimsPending);
+        // Native SOL leftovers go to the organizer together with the tournament's rent
+        if !reward_pool.is_native() {
+            let organizer_ata =
+                ctx.accounts.organizer_ata.as_ref().ok_or(GenomeError::InvalidToken)?;
+            reward_pool.pay(organizer_ata.to_account_info(), leftover)?;
+        }
+    }
+
+    if let Some(reward_pool_ata) = &ctx.accounts.reward_pool_ata {
+        let accounts = CloseAccount {
+            account: reward_pool_ata.to_account_info(),
+            destination: ctx.accounts.organizer.to_account_info(),
+            authority: tournament.to_account_info(),
+        };
+        let cpi = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            accounts,
+            signer,
+        );
+        close_account(cpi)?;
+    }
+
+    emit!(TournamentClosed {
+        tournament_id,
+        leftover
+    });
+
+    Ok(())
+}
+
+fn schedule_payer<'info>(
+    schedule_payer: &Option<UncheckedAccount<'info>>,
+    payer: Pubkey,
+) -> Result<AccountInfo<'info>> {
+    let schedule_payer = schedule_payer.as_ref().ok_or(GenomeError::NotAllowed)?;
+    require_keys_eq!(schedule_payer.key(), payer, GenomeError::NotAllowed);
+    Ok(schedule_payer.to_account_info())
+}
+
+#[derive(Accounts)]
+#[instruction(tournament_id: u32)]
+pub struct CloseTournament<'info> {
+    pub caller: Signer<'info>,
+
+    #[account(mut, address = tournament.organizer @ GenomeError::NotAllowed)]
+    pub organizer: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        close = organizer,
+        seeds = [GENOME_ROOT, TOURNAMENT, tournament_id.to_le_bytes().as_ref()],
+        constraint = tournament.status == TournamentStatus::Finished
+            || tournament.status == TournamentStatus::Canceled @ GenomeError::InvalidStatus,
+        constraint = tournament.registered_teams == 0 @ GenomeError::ClaimsPending,
+        constraint = tournament.sponsorships == 0 @ GenomeError::ClaimsPending,
+        constraint = tournament.bonus_mints.is_empty() @ GenomeError::BonusPoolsOpen,
+        bump
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    #[account(
+        mut,
+        close = organizer,
+        seeds = [GENOME_ROOT, CONSENSUS, tournament_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub consensus: Account<'info, Consensus>,
+
+    #[account(
+        mut,
+        close = organizer,
+        seeds = [GENOME_ROOT, FINISH, tournament_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub finish_meta_data: Box<Account<'info, FinishMetaData>>,
+
+    #[account(
+        mut,
+        close = organizer,
+        seeds = [GENOME_ROOT, BLOOM, tournament_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub bloom_filter: Box<Account<'info, BloomFilter>>,
+
+    #[account(
+        mut,
+        close = organizer,
+        seeds = [GENOME_ROOT, METADATA, tournament_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub metadata: Box<Account<'info, TournamentMetadata>>,
+
+    #[account(mut, seeds = [GENOME_ROOT, BRACKET, tournament_id.to_le_bytes().as_ref()], bump)]
+    pub bracket: Option<Box<Account<'info, Bracket>>>,
+
+    #[account(mut, seeds = [GENOME_ROOT, LEAGUE, tournament_id.to_le_bytes().as_ref()], bump)]
+    pub league: Option<Box<Account<'info, League>>>,
+
+    /// CHECK: checked against the payer recorded in the bracket or the league
+    #[account(mut)]
+    pub schedule_payer: Option<UncheckedAccount<'info>>,
+
+    #[account(constraint = asset_mint.key() == tournament.config.asset_mint @ GenomeError::InvalidNome)]
+    pub asset_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = asset_mint,
+        associated_token::authority = organizer,
+    )]
+    pub organizer_ata: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        mut,
+        associated_token::mint = asset_mint,
+        associated_token::authority = tournament,
+    )]
+    pub reward_pool_ata: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[event]
+pub struct TournamentClosed {
+    pub tournament_id: u32,
+    pub leftover: u64,
+}
@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    bracket::Bracket,
+    data::{Tournament, TournamentFormat, TournamentStatus},
+    error::GenomeError,
+    instructions::completed_team_captains,
+    BRACKET, GENOME_ROOT, TOURNAMENT,
+};
+
+/// Builds the bracket from every completed team of the tournament. The team PDAs are
+/// passed in `remaining_accounts` in any order, the bracket is seeded by captain key.
+pub fn handle_create_bracket<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CreateBracket<'info>>,
+    tournament_id: u32,
+) -> Result<()> {
+    let captains =
+        completed_team_captains(ctx.program_id, &ctx.accounts.tournament, ctx.remaining_accounts)?;
+
+    ctx.accounts.bracket.set_inner(Bracket::new(&captains));
+    ctx.accounts.bracket.payer = ctx.accounts.payer.key();
+
+    emit!(BracketCreated {
+        tournament_id,
+        captains
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(tournament_id: u32)]
+pub struct CreateBracket<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [GENOME_ROOT, TOURNAMENT, tournament_id.to_le_bytes().as_ref()],
+        constraint = tournament.status == TournamentStatus::Started @ GenomeError::InvalidStatus,
+        constraint = tournament.config.format == TournamentFormat::SingleElimination @ GenomeError::InvalidFormat,
+        bump
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = Bracket::space(tournament.team_count as usize),
+        seeds = [GENOME_ROOT, BRACKET, tournament_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub bracket: Box<Account<'info, Bracket>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct BracketCreated {
+    pub tournament_id: u32,
+    pub captains: Vec<Pubkey>,
+}
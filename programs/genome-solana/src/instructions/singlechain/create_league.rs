@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    data::{Tournament, TournamentFormat, TournamentStatus},
+    error::GenomeError,
+    instructions::completed_team_captains,
+    league::League,
+    GENOME_ROOT, LEAGUE, TOURNAMENT,
+};
+
+/// Builds the round-robin schedule from every completed team of the tournament. The team
+/// PDAs are passed in `remaining_accounts` in any order.
+pub fn handle_create_league<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CreateLeague<'info>>,
+    tournament_id: u32,
+) -> Result<()> {
+    let captains =
+        completed_team_captains(ctx.program_id, &ctx.accounts.tournament, ctx.remaining_accounts)?;
+
+    ctx.accounts.league.set_inner(League::new(&captains));
+    ctx.accounts.league.payer = ctx.accounts.payer.key();
+
+    emit!(LeagueCreated {
+        tournament_id,
+        captains
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(tournament_id: u32)]
+pub struct CreateLeague<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [GENOME_ROOT, TOURNAMENT, tournament_id.to_le_bytes().as_ref()],
+        constraint = tournament.status == TournamentStatus::Started @ GenomeError::InvalidStatus,
+        constraint = tournament.config.format == TournamentFormat::League @ GenomeError::InvalidFormat,
+        bump
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = League::space(tournament.team_count as usize),
+        seeds = [GENOME_ROOT, LEAGUE, tournament_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub league: Box<Account<'info, League>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct LeagueCreated {
+    pub tournament_id: u32,
+    pub captains: Vec<Pubkey>,
+}
@@ -1,4 +1,4 @@
-use anchor_lang::{prelude::*, solana_program::pubkey::PUBKEY_BYTES};
+use anchor_lang::prelude::*;
 use anchor_spl::{
     associated_token::AssociatedToken,
     token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked},
@@ -7,19 +7,24 @@ use anchor_spl::{
 use crate::{
     data::{
         BloomFilter, Consensus, FinishMetaData, GenomeSingleConfig, Role, RoleInfo, RoleList,
-        TokenInfo, Tournament, TournamentConfig,
+        Sponsorship, TokenInfo, Tournament, TournamentConfig, TournamentMetadata,
     },
     error::GenomeError,
-    utils::{calculate_bloom_memory, initialize_bloom_filter, validate_params},
-    BLOOM, CONSENSUS, FINISH, GENOME_ROOT, ROLE, SINGLE_CONFIG, TOKEN, TOURNAMENT,
+    reward_pool::RewardPool,
+    utils::{calculate_bloom_memory, initialize_bloom_filter, validate_metadata, validate_params},
+    BLOOM, CONSENSUS, FINISH, GENOME_ROOT, METADATA, ROLE, SINGLE_CONFIG, SPONSORSHIP, TOKEN,
+    TOURNAMENT,
 };
 
 pub(crate) fn handle_create_tournament(
     ctx: Context<CreateTournament>,
     tournament_config: TournamentConfig,
+    metadata: TournamentMetadata,
 ) -> Result<()> {
     let tournament = &mut ctx.accounts.tournament;
     validate_params(&tournament_config, &ctx.accounts.config, &ctx.accounts.token_info)?;
+    validate_metadata(&metadata)?;
+    ctx.accounts.metadata.set_inner(metadata);
     initialize_bloom_filter(
         &tournament_config,
         &ctx.accounts.config.false_precision,
@@ -29,17 +34,36 @@ pub(crate) fn handle_create_tournament(
     tournament.initialize(*id, ctx.accounts.organizer.key(), tournament_config.clone());
     *id += 1;
 
-    if tournament.config.sponsor_pool > 0 {
-        let accounts = TransferChecked {
-            from: ctx.accounts.sponsor_ata.to_account_info(),
-            to: ctx.accounts.reward_pool_ata.to_account_info(),
-            mint: ctx.accounts.asset_mint.to_account_info(),
-            authority: ctx.accounts.organizer.to_account_info(),
-        };
+    // SPL sponsor pools are moved by the organizer as a delegate of the sponsor's token
+    // account, native SOL has to be sent by the sponsor themselves.
+    let reward_pool = RewardPool::new(
+        tournament,
+        ctx.bumps.tournament,
+        &ctx.accounts.asset_mint,
+        ctx.accounts.reward_pool_ata.as_deref(),
+        &ctx.accounts.token_program,
+    )?;
+    let depositor = if reward_pool.is_native() {
+        require!(
+            ctx.accounts.sponsor.is_signer || tournament.config.sponsor_pool == 0,
+            GenomeError::NotAllowed
+        );
+        ctx.accounts.sponsor.to_account_info()
+    } else {
+        ctx.accounts.organizer.to_account_info()
+    };
+    let sponsor_pool = reward_pool.deposit(
+        ctx.accounts.sponsor_ata.as_ref().map(|ata| ata.to_account_info()),
+        depositor,
+        ctx.accounts.system_program.to_account_info(),
+        tournament.config.sponsor_pool,
+    )?;
+    tournament.record_sponsorship(sponsor_pool);
 
-        let cpi = CpiContext::new(ctx.accounts.token_program.to_account_info(), accounts);
-        transfer_checked(cpi, tournament.config.sponsor_pool, ctx.accounts.asset_mint.decimals)?;
-    }
+    let sponsorship = &mut ctx.accounts.sponsorship;
+    sponsorship.sponsor = ctx.accounts.sponsor.key();
+    sponsorship.amount = sponsor_pool;
+    tournament.sponsorships += 1;
 
     if ctx.accounts.role_info.claim >= ctx.accounts.config.platform_fee {
         ctx.accounts.role_info.claim -= ctx.accounts.config.platform_fee;
@@ -51,7 +75,7 @@ pub(crate) fn handle_create_tournament(
             authority: ctx.accounts.organizer.to_account_info(),
         };
 
-        let cpi = CpiContext::new(ctx.accounts.token_program.to_account_info(), accounts);
+        let cpi = CpiContext::new(ctx.accounts.nome_token_program.to_account_info(), accounts);
         transfer_checked(cpi, ctx.accounts.config.platform_fee, ctx.accounts.nome_mint.decimals)?;
     }
 
@@ -69,6 +93,7 @@ pub(crate) struct CreateTournament<'info> {
     #[account(mut)]
     organizer: Signer<'info>,
 
+    #[account(mut)]
     sponsor: SystemAccount<'info>,
 
     #[account(mut, seeds = [GENOME_ROOT, SINGLE_CONFIG], bump)]
@@ -108,12 +133,30 @@ pub(crate) struct CreateTournament<'info> {
     #[account(
         init,
         payer = organizer,
-        space = FinishMetaData::DISCRIMINATOR.len() + FinishMetaData::INIT_SPACE + PUBKEY_BYTES * verifier_list.accounts.len(),
+        space = FinishMetaData::space(verifier_list.accounts.len(), tournament_data.prize_distribution.len()),
         seeds = [GENOME_ROOT, FINISH, config.tournament_nonce.to_le_bytes().as_ref()],
         bump
     )]
     finish_meta_data: Account<'info, FinishMetaData>,
 
+    #[account(
+        init,
+        payer = organizer,
+        space = TournamentMetadata::DISCRIMINATOR.len() + TournamentMetadata::INIT_SPACE,
+        seeds = [GENOME_ROOT, METADATA, config.tournament_nonce.to_le_bytes().as_ref()],
+        bump
+    )]
+    metadata: Box<Account<'info, TournamentMetadata>>,
+
+    #[account(
+        init,
+        payer = organizer,
+        space = Sponsorship::DISCRIMINATOR.len() + Sponsorship::INIT_SPACE,
+        seeds = [GENOME_ROOT, SPONSORSHIP, config.tournament_nonce.to_le_bytes().as_ref(), sponsor.key().as_ref()],
+        bump
+    )]
+    sponsorship: Box<Account<'info, Sponsorship>>,
+
     asset_mint: InterfaceAccount<'info, Mint>,
 
     #[account(constraint = nome_mint.key() == config.nome_mint @ GenomeError::InvalidNome)]
@@ -132,7 +175,7 @@ pub(crate) struct CreateTournament<'info> {
         associated_token::authority = tournament,
         associated_token::token_program = token_program,
     )]
-    reward_pool_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+    reward_pool_ata: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
 
     #[account(
         init_if_needed,
@@ -141,12 +184,13 @@ pub(crate) struct CreateTournament<'info> {
         associated_token::authority = sponsor,
         associated_token::token_program = token_program,
     )]
-    sponsor_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+    sponsor_ata: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
 
     #[account(
         mut,
         associated_token::mint = nome_mint,
         associated_token::authority = organizer,
+        associated_token::token_program = nome_token_program,
     )]
     organizer_ata: Box<InterfaceAccount<'info, TokenAccount>>,
 
@@ -154,13 +198,14 @@ pub(crate) struct CreateTournament<'info> {
         mut,
         associated_token::mint = nome_mint,
         associated_token::authority = config.platform_wallet,
+        associated_token::token_program = nome_token_program,
     )]
     platform_ata: Box<InterfaceAccount<'info, TokenAccount>>,
 
     #[account(
         init,
         payer = organizer,
-        space = BloomFilter::DISCRIMINATOR.len() + calculate_bloom_memory(tournament_data.max_teams * tournament_data.team_size, config.false_precision)?,
+        space = BloomFilter::space(calculate_bloom_memory(tournament_data.max_teams * tournament_data.team_size, config.false_precision)?),
         seeds = [GENOME_ROOT, BLOOM, config.tournament_nonce.to_le_bytes().as_ref()],
         bump
     )]
@@ -169,6 +214,8 @@ pub(crate) struct CreateTournament<'info> {
     associated_token_program: Program<'info, AssociatedToken>,
     system_program: Program<'info, System>,
     token_program: Interface<'info, TokenInterface>,
+    /// The NOME mint may live under a different token program than the asset mint.
+    nome_token_program: Interface<'info, TokenInterface>,
 }
 
 #[event]
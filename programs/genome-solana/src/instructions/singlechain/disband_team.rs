@@ -0,0 +1,141 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    data::{BloomFilter, Payout, Tournament, TournamentStatus},
+    error::GenomeError,
+    reward_pool::RewardPool,
+    team::Team,
+    utils::release_bloom_slots,
+    BLOOM, GENOME_ROOT, TEAM, TOURNAMENT,
+};
+
+/// Refunds every member of the team and closes it. Accepted members who paid for themselves
+/// are refunded to their token accounts passed in `remaining_accounts` in team order, or to
+/// their wallets for native SOL tournaments.
+pub fn handle_disband_team<'info>(
+    ctx: Context<'_, '_, 'info, 'info, DisbandTeam<'info>>,
+    tournament_id: u32,
+) -> Result<()> {
+    let tournament = &mut ctx.accounts.tournament;
+    let team = &ctx.accounts.team;
+    let captain = ctx.accounts.captain.key();
+    let asset_mint = tournament.config.asset_mint;
+
+    if team.completed {
+        tournament.team_count -= 1;
+        tournament.entry_pool -= team.deposits();
+    }
+    tournament.registered_teams -= 1;
+
+    let reward_pool = RewardPool::new(
+        tournament,
+        ctx.bumps.tournament,
+        &ctx.accounts.mint,
+        ctx.accounts.reward_pool_ata.as_deref(),
+        &ctx.accounts.token_program,
+    )?;
+
+    let self_paid: Vec<(Pubkey, u64)> = team
+        .participants
+        .iter()
+        .filter(|p| p.accepted && !p.paid_by_captain)
+        .map(|p| (p.pubkey, p.deposit))
+        .collect();
+    require!(
+        ctx.remaining_accounts.len() == self_paid.len(),
+        GenomeError::InvalidRemainingAccounts
+    );
+
+    for ((member, deposit), account) in self_paid.iter().zip(ctx.remaining_accounts.iter()) {
+        if reward_pool.is_native() {
+            require!(account.key() == *member, GenomeError::ParticipantNotFound);
+        } else {
+            let member_ata = InterfaceAccount::<TokenAccount>::try_from(account)?;
+            require!(
+                member_ata.owner == *member && member_ata.mint == asset_mint,
+                GenomeError::ParticipantNotFound
+            );
+        }
+        tournament.record_payout(Payout::Refund, *deposit)?;
+        reward_pool.pay(account.clone(), *deposit)?;
+    }
+
+    let paid_by_captain: u64 =
+        team.participants.iter().filter(|p| p.paid_by_captain).map(|p| p.deposit).sum();
+    if paid_by_captain > 0 {
+        let refund_to = reward_pool
+            .recipient(&ctx.accounts.captain_ata, ctx.accounts.captain.to_account_info())?;
+        tournament.record_payout(Payout::Refund, paid_by_captain)?;
+        reward_pool.pay(refund_to, paid_by_captain)?;
+    }
+
+    let members: Vec<Pubkey> =
+        team.participants.iter().filter(|p| p.accepted).map(|p| p.pubkey).collect();
+    release_bloom_slots(
+        &mut ctx.accounts.bloom_filter,
+        ctx.accounts.captain.to_account_info(),
+        &members,
+    )?;
+
+    emit!(TeamDisbanded {
+        tournament_id,
+        captain,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(tournament_id: u32)]
+pub struct DisbandTeam<'info> {
+    #[account(mut)]
+    pub captain: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GENOME_ROOT, TOURNAMENT, tournament_id.to_le_bytes().as_ref()],
+        constraint = tournament.status == TournamentStatus::New @ GenomeError::InvalidStatus,
+        bump
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    #[account(
+        mut,
+        seeds = [GENOME_ROOT, TEAM, tournament_id.to_le_bytes().as_ref(), captain.key().as_ref()],
+        bump,
+        close = captain
+    )]
+    pub team: Account<'info, Team>,
+
+    #[account(address = tournament.config.asset_mint @ GenomeError::InvalidToken)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = captain,
+        associated_token::token_program = token_program,
+    )]
+    pub captain_ata: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = tournament,
+        associated_token::token_program = token_program,
+    )]
+    pub reward_pool_ata: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(mut, seeds = [GENOME_ROOT, BLOOM, tournament_id.to_le_bytes().as_ref()], bump)]
+    pub bloom_filter: Box<Account<'info, BloomFilter>>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[event]
+pub struct TeamDisbanded {
+    pub tournament_id: u32,
+    pub captain: Pubkey,
+}
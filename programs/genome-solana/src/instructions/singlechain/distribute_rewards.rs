@@ -0,0 +1,172 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_2022::Token2022,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{
+    bonus::{load_bonus_payouts, pay_bonuses},
+    data::{FinishMetaData, Payout, Tournament, TournamentStatus},
+    error::GenomeError,
+    instructions::RewardClaimed,
+    reward_pool::RewardPool,
+    soulbound::SoulboundMint,
+    team::Team,
+    FINISH, GENOME_ROOT, TEAM, TOURNAMENT, TROPHY,
+};
+
+/// Pays the reward and mints the trophy to team members on their behalf. `remaining_accounts`
+/// starts with the bonus pool accounts laid out as in `claim_reward`, followed by one group per
+/// member: the asset token account (the member's wallet once more in native SOL tournaments),
+/// the member's wallet, the trophy token account and then a token account for every bonus
+/// pool. The caller pays for the trophy accounts. Members come in any order and those who have
+/// already claimed are skipped, so a keeper can settle a big team over several transactions.
+pub fn handle_distribute_rewards<'info>(
+    ctx: Context<'_, '_, 'info, 'info, DistributeRewards<'info>>,
+    tournament_id: u32,
+    captain: Pubkey,
+) -> Result<()> {
+    let finish_meta_data = &ctx.accounts.finish_meta_data;
+    let place = finish_meta_data.place_of(&captain).ok_or(GenomeError::NotWinner)?;
+    let reward = finish_meta_data.rewards[place];
+
+    let tournament = &mut ctx.accounts.tournament;
+    let now = Clock::get()?.unix_timestamp as u64;
+    require!(tournament.claims_open(now), GenomeError::ClaimPeriodOver);
+
+    let team = &mut ctx.accounts.team;
+    let asset_mint = tournament.config.asset_mint;
+    let reward_pool = RewardPool::new(
+        tournament,
+        ctx.bumps.tournament,
+        &ctx.accounts.asset_mint,
+        ctx.accounts.reward_pool_ata.as_deref(),
+        &ctx.accounts.token_program,
+    )?;
+
+    let tournament_id_bytes = tournament_id.to_le_bytes();
+    let tournament_seeds = &[
+        GENOME_ROOT,
+        TOURNAMENT,
+        tournament_id_bytes.as_ref(),
+        &[ctx.bumps.tournament],
+    ];
+    let signer = &[&tournament_seeds[..]];
+
+    let bonus_count = tournament.bonus_mints.len();
+    require!(ctx.remaining_accounts.len() >= 3 * bonus_count, GenomeError::InvalidBonusPool);
+    let (pool_accounts, member_accounts) = ctx.remaining_accounts.split_at(3 * bonus_count);
+    let payouts = load_bonus_payouts(tournament, pool_accounts)?;
+    let share = tournament.config.prize_distribution[place];
+
+    let trophy = SoulboundMint {
+        payer: ctx.accounts.caller.to_account_info(),
+        authority: ctx.accounts.trophy_authority.to_account_info(),
+        mint: ctx.accounts.trophy_mint.to_account_info(),
+        token_program: ctx.accounts.token_2022_program.to_account_info(),
+        associated_token_program: ctx.accounts.associated_token_program.to_account_info(),
+        system_program: ctx.accounts.system_program.to_account_info(),
+        authority_bump: ctx.bumps.trophy_authority,
+        mint_bump: ctx.bumps.trophy_mint,
+    };
+
+    let group_len = 3 + bonus_count;
+    require!(member_accounts.len() % group_len == 0, GenomeError::InvalidBonusPool);
+    for group in member_accounts.chunks(group_len) {
+        let (account, wallet, trophy_ata) = (&group[0], &group[1], &group[2]);
+        let recipients = &group[3..];
+        let participant = if reward_pool.is_native() {
+            account.key()
+        } else {
+            let member_ata = InterfaceAccount::<TokenAccount>::try_from(account)?;
+            require!(member_ata.mint == asset_mint, GenomeError::InvalidToken);
+            member_ata.owner
+        };
+        require_keys_eq!(wallet.key(), participant, GenomeError::ParticipantNotFound);
+        let unclaimed = team
+            .participants
+            .iter()
+            .find(|p| p.pubkey == participant && p.accepted)
+            .map(|p| !p.claimed)
+            .ok_or(GenomeError::ParticipantNotFound)?;
+        if !unclaimed {
+            continue;
+        }
+        team.reward_participant(&participant)?;
+        tournament.record_payout(Payout::Reward, reward)?;
+        reward_pool.pay(account.clone(), reward)?;
+
+        pay_bonuses(
+            &payouts,
+            recipients,
+            &participant,
+            share,
+            tournament,
+            ctx.accounts.token_program.to_account_info(),
+            signer,
+        )?;
+
+        trophy.award_trophy(tournament_id, place, &captain, wallet.clone(), trophy_ata.clone())?;
+
+        emit!(RewardClaimed {
+            tournament_id,
+            participant,
+            amount: reward
+        });
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(tournament_id: u32, captain: Pubkey)]
+pub struct DistributeRewards<'info> {
+    #[account(mut)]
+    pub caller: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GENOME_ROOT, TOURNAMENT, tournament_id.to_le_bytes().as_ref()],
+        constraint = tournament.status == TournamentStatus::Finished @ GenomeError::InvalidStatus,
+        bump,
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    #[account(
+        mut,
+        seeds = [GENOME_ROOT, TEAM, tournament_id.to_le_bytes().as_ref(), captain.as_ref()],
+        bump,
+    )]
+    pub team: Account<'info, Team>,
+
+    #[account(constraint = asset_mint.key() == tournament.config.asset_mint @ GenomeError::InvalidNome)]
+    pub asset_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(seeds = [GENOME_ROOT, FINISH, tournament_id.to_le_bytes().as_ref()], bump)]
+    pub finish_meta_data: Account<'info, FinishMetaData>,
+
+    #[account(
+        mut,
+        associated_token::mint = asset_mint,
+        associated_token::authority = tournament,
+    )]
+    pub reward_pool_ata: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// CHECK: PDA used as the trophy mint and metadata authority
+    #[account(seeds = [GENOME_ROOT, TROPHY], bump)]
+    pub trophy_authority: UncheckedAccount<'info>,
+
+    /// CHECK: the team's trophy mint, created on the first claim of the team
+    #[account(
+        mut,
+        seeds = [GENOME_ROOT, TROPHY, tournament_id.to_le_bytes().as_ref(), captain.as_ref()],
+        bump
+    )]
+    pub trophy_mint: UncheckedAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub token_2022_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
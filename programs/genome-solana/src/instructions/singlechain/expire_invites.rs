@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    data::{Payout, Tournament, TournamentStatus},
+    error::GenomeError,
+    reward_pool::RewardPool,
+    team::Team,
+    GENOME_ROOT, TEAM, TOURNAMENT,
+};
+
+pub fn handle_expire_invites(
+    ctx: Context<ExpireInvites>,
+    tournament_id: u32,
+    captain: Pubkey,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let tournament = &mut ctx.accounts.tournament;
+    let team = &mut ctx.accounts.team;
+
+    require!(
+        ctx.accounts.caller.key() == captain
+            || clock.unix_timestamp as u64 > team.invite_expiration,
+        GenomeError::InviteNotExpired
+    );
+
+    let refunded = team.expire_invites();
+    if refunded > 0 {
+        let reward_pool = RewardPool::new(
+            tournament,
+            ctx.bumps.tournament,
+            &ctx.accounts.mint,
+            ctx.accounts.reward_pool_ata.as_deref(),
+            &ctx.accounts.token_program,
+        )?;
+        let refund_to = reward_pool
+            .recipient(&ctx.accounts.captain_ata, ctx.accounts.captain_wallet.to_account_info())?;
+        tournament.record_payout(Payout::Refund, refunded)?;
+        reward_pool.pay(refund_to, refunded)?;
+    }
+
+    emit!(InvitesExpired {
+        tournament_id,
+        captain,
+        refunded,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(tournament_id: u32, captain: Pubkey)]
+pub struct ExpireInvites<'info> {
+    pub caller: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GENOME_ROOT, TOURNAMENT, tournament_id.to_le_bytes().as_ref()],
+        constraint = tournament.status == TournamentStatus::New @ GenomeError::InvalidStatus,
+        bump
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    #[account(
+        mut,
+        seeds = [GENOME_ROOT, TEAM, tournament_id.to_le_bytes().as_ref(), captain.as_ref()],
+        bump
+    )]
+    pub team: Account<'info, Team>,
+
+    #[account(address = tournament.config.asset_mint @ GenomeError::InvalidToken)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = captain,
+        associated_token::token_program = token_program,
+    )]
+    pub captain_ata: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(mut, address = captain)]
+    pub captain_wallet: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = tournament,
+        associated_token::token_program = token_program,
+    )]
+    pub reward_pool_ata: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[event]
+pub struct InvitesExpired {
+    pub tournament_id: u32,
+    pub captain: Pubkey,
+    pub refunded: u64,
+}
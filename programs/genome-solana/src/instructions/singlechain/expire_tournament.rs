@@ -0,0 +1,57 @@
+use crate::{
+    data::{RoleInfo, Tournament, TournamentStatus},
+    error::GenomeError,
+    GenomeSingleConfig, Role, GENOME_ROOT, ROLE, SINGLE_CONFIG, TOURNAMENT,
+};
+use anchor_lang::prelude::*;
+
+pub fn handle_expire_tournament(ctx: Context<ExpireTournament>, tournament_id: u32) -> Result<()> {
+    let clock = Clock::get()?;
+    let tournament = &mut ctx.accounts.tournament;
+
+    require!(
+        tournament.expired(ctx.accounts.config.claim_period, clock.unix_timestamp as u64),
+        GenomeError::NotExpired
+    );
+
+    ctx.accounts.role_info_org.claim += ctx.accounts.config.platform_fee;
+    tournament.settle(
+        TournamentStatus::Canceled,
+        ctx.accounts.config.claim_period,
+        clock.unix_timestamp as u64,
+    )?;
+    emit!(TournamentExpired { tournament_id });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(tournament_id: u32)]
+pub struct ExpireTournament<'info> {
+    pub caller: Signer<'info>,
+
+    #[account(seeds = [GENOME_ROOT, SINGLE_CONFIG], bump)]
+    pub config: Account<'info, GenomeSingleConfig>,
+
+    #[account(
+        mut,
+        seeds = [GENOME_ROOT, TOURNAMENT, tournament_id.to_le_bytes().as_ref()],
+        constraint = tournament.status == TournamentStatus::New
+            || tournament.status == TournamentStatus::Started @ GenomeError::InvalidStatus,
+        bump
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    #[account(
+        mut,
+        seeds = [GENOME_ROOT, ROLE, tournament.organizer.as_ref()],
+        bump,
+        constraint = role_info_org.roles.contains(&Role::Organizer) @ GenomeError::NotAllowed,
+    )]
+    pub role_info_org: Account<'info, RoleInfo>,
+}
+
+#[event]
+pub struct TournamentExpired {
+    pub tournament_id: u32,
+}
@@ -1,100 +1,126 @@
 use std::collections::HashMap;
 
 use crate::{
+    bracket::Bracket,
     data::{
-        Consensus, FinishMetaData, GenomeSingleConfig, RoleInfo, RoleList, Tournament,
-        TournamentStatus,
+        Consensus, FinishMetaData, GenomeSingleConfig, Payout, RoleInfo, RoleList, Tournament,
+        TournamentFormat, TournamentStatus, VerifierStake,
     },
     error::GenomeError,
-    Role, CONSENSUS, FINISH, GENOME_ROOT, ROLE, SINGLE_CONFIG, TOURNAMENT,
+    fees::{reaches_rate, split_reward_pool},
+    league::League,
+    reward_pool::RewardPool,
+    Role, BRACKET, CONSENSUS, FINISH, GENOME_ROOT, LEAGUE, ROLE, SINGLE_CONFIG, STAKE, TOURNAMENT,
 };
 use anchor_lang::prelude::*;
-use anchor_spl::token_interface::{
-    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
-};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 pub fn handle_finish_tournament(
     ctx: Context<FinishTournament>,
     tournament_id: u32,
-    captain_winner: Pubkey,
+    placements: Vec<Pubkey>,
 ) -> Result<()> {
     let config = &ctx.accounts.config;
-    let role_info = &mut ctx.accounts.role_info;
     let consensus = &mut ctx.accounts.consensus;
     let tournament = &mut ctx.accounts.tournament;
     let finish_meta = &mut ctx.accounts.finish_meta_data;
     let verifier_list = &ctx.accounts.verifier_list.accounts;
     let verifier_pk = ctx.accounts.verifier.key();
 
+    let places_count = tournament.config.prize_distribution.len();
+    let placements = match tournament.config.format {
+        TournamentFormat::FreeForm => {
+            validate_placements(&placements, places_count)?;
+            placements
+        }
+        TournamentFormat::SingleElimination => {
+            require!(placements.is_empty(), GenomeError::InvalidPlacements);
+            let bracket = ctx.accounts.bracket.as_ref().ok_or(GenomeError::InvalidFormat)?;
+            bracket.placements(places_count).ok_or(GenomeError::BracketNotFinished)?
+        }
+        TournamentFormat::League => {
+            require!(placements.is_empty(), GenomeError::InvalidPlacements);
+            let league = ctx.accounts.league.as_ref().ok_or(GenomeError::InvalidFormat)?;
+            league
+                .placements(places_count, &tournament.config.tiebreakers)
+                .ok_or(GenomeError::LeagueNotFinished)?
+        }
+    };
+
     let verifier_index =
         verifier_list.iter().position(|&v| v == verifier_pk).expect("verifier not found");
 
     require!((consensus.finish_votes >> verifier_index) & 1 == 0, GenomeError::AlreadyVoted);
 
-    consensus.finish_votes |= 1 << verifier_index;
-    finish_meta.finish_votes.push(captain_winner);
-    role_info.claim += config.verifier_fee;
-
-    let votes = consensus.finish_votes.count_ones() as u64;
-    let total = verifier_list.len() as u64;
+    let now = Clock::get()?.unix_timestamp as u64;
+    let weight = ctx.accounts.stake.vote_weight(now, config.stake_lock_period)?;
+    ctx.accounts.stake.unsettled_votes += 1;
 
-    if votes * 10000 >= total * config.consensus_rate {
-        let mut counts = HashMap::new();
-        for pk in finish_meta.finish_votes.iter() {
-            *counts.entry(pk).or_insert(0) += 1;
+    consensus.finish_votes |= 1 << verifier_index;
+    consensus.finish_weight += weight;
+    finish_meta.finish_votes.push(placements);
+    finish_meta.vote_weights.push(weight);
+    finish_meta.voters.push(verifier_pk);
+
+    if reaches_rate(consensus.finish_weight, config.total_stake, config.consensus_rate) {
+        let mut weights = HashMap::new();
+        for (vote, &weight) in finish_meta.finish_votes.iter().zip(&finish_meta.vote_weights) {
+            *weights.entry(vote).or_insert(0) += weight;
         }
-        let winner = *counts
+        let placements = weights
             .into_iter()
-            .max_by_key(|&(_, count)| count)
-            .map(|(pk, _)| pk)
-            .expect("List of captains can't be empty");
-
-        let tournament_seeds = &[
-            GENOME_ROOT,
-            TOURNAMENT,
-            &tournament.id.to_le_bytes(),
-            &[ctx.bumps.tournament],
-        ];
-        let signer = &[&tournament_seeds[..]];
-
-        let reward_pool = tournament.config.sponsor_pool
-            + tournament.config.entry_fee * tournament.team_count as u64;
-        let organizer_reward =
-            (reward_pool as f64 * (tournament.config.organizer_fee as f64 / 10000f64)) as u64;
-        let reward_per_winner =
-            (reward_pool - organizer_reward) / tournament.config.team_size as u64;
-
-        let accounts = TransferChecked {
-            from: ctx.accounts.reward_pool_ata.to_account_info(),
-            to: ctx.accounts.organizer_ata.to_account_info(),
-            mint: ctx.accounts.asset_mint.to_account_info(),
-            authority: tournament.to_account_info(),
-        };
-        let cpi = CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            accounts,
-            signer,
-        );
-        transfer_checked(cpi, organizer_reward, ctx.accounts.asset_mint.decimals)?;
-
-        finish_meta.captain_winner = winner;
-        finish_meta.reward = reward_per_winner;
-
-        tournament.status = TournamentStatus::Finished;
+            .max_by_key(|&(_, weight)| weight)
+            .map(|(vote, _)| vote.clone())
+            .expect("List of placements can't be empty");
+
+        let (rewards, organizer_reward) = split_reward_pool(
+            tournament.sponsor_pool + tournament.entry_pool,
+            tournament.config.organizer_fee,
+            &tournament.config.prize_distribution,
+            tournament.config.team_size,
+        )?;
+
+        let pool = RewardPool::new(
+            tournament,
+            ctx.bumps.tournament,
+            &ctx.accounts.asset_mint,
+            ctx.accounts.reward_pool_ata.as_deref(),
+            &ctx.accounts.token_program,
+        )?;
+        let organizer_to =
+            pool.recipient(&ctx.accounts.organizer_ata, ctx.accounts.organizer.to_account_info())?;
+        tournament.record_payout(Payout::OrganizerFee, organizer_reward)?;
+        pool.pay(organizer_to, organizer_reward)?;
+
+        finish_meta.placements = placements.clone();
+        finish_meta.rewards = rewards;
+
+        tournament.settle(TournamentStatus::Finished, config.claim_period, now)?;
         emit!(TournamentFinished {
             tournament_id,
-            winner
+            placements
         });
     }
 
     Ok(())
 }
 
+fn validate_placements(placements: &[Pubkey], places_count: usize) -> Result<()> {
+    require!(placements.len() == places_count, GenomeError::InvalidPlacements);
+
+    for (i, captain) in placements.iter().enumerate() {
+        require!(!placements[i + 1..].contains(captain), GenomeError::InvalidPlacements);
+    }
+
+    Ok(())
+}
+
 #[derive(Accounts)]
 #[instruction(tournament_id: u32)]
 pub struct FinishTournament<'info> {
     pub verifier: Signer<'info>,
 
+    #[account(mut, address = tournament.organizer @ GenomeError::NotAllowed)]
     pub organizer: SystemAccount<'info>,
 
     #[account(
@@ -104,6 +130,9 @@ pub struct FinishTournament<'info> {
     )]
     pub role_info: Account<'info, RoleInfo>,
 
+    #[account(mut, seeds = [GENOME_ROOT, STAKE, verifier.key().as_ref()], bump)]
+    pub stake: Account<'info, VerifierStake>,
+
     #[account(seeds = [GENOME_ROOT, ROLE, Role::Verifier.to_seed()], bump)]
     pub verifier_list: Account<'info, RoleList>,
 
@@ -124,6 +153,12 @@ pub struct FinishTournament<'info> {
     #[account(mut, seeds = [GENOME_ROOT, FINISH, tournament_id.to_le_bytes().as_ref()], bump)]
     pub finish_meta_data: Account<'info, FinishMetaData>,
 
+    #[account(seeds = [GENOME_ROOT, BRACKET, tournament_id.to_le_bytes().as_ref()], bump)]
+    pub bracket: Option<Box<Account<'info, Bracket>>>,
+
+    #[account(seeds = [GENOME_ROOT, LEAGUE, tournament_id.to_le_bytes().as_ref()], bump)]
+    pub league: Option<Box<Account<'info, League>>>,
+
     #[account(constraint = asset_mint.key() == tournament.config.asset_mint @ GenomeError::InvalidNome)]
     pub asset_mint: InterfaceAccount<'info, Mint>,
 
@@ -132,14 +167,14 @@ pub struct FinishTournament<'info> {
         associated_token::mint = asset_mint,
         associated_token::authority = organizer,
     )]
-    pub organizer_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub organizer_ata: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
 
     #[account(
         mut,
         associated_token::mint = asset_mint,
         associated_token::authority = tournament,
     )]
-    pub reward_pool_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub reward_pool_ata: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
 
     pub token_program: Interface<'info, TokenInterface>,
 }
@@ -147,5 +182,5 @@ pub struct FinishTournament<'info> {
 #[event]
 pub struct TournamentFinished {
     pub tournament_id: u32,
-    pub winner: Pubkey,
+    pub placements: Vec<Pubkey>,
 }
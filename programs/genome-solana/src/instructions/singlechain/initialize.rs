@@ -5,15 +5,26 @@ use anchor_spl::{
 };
 
 use crate::{
-    data::GenomeSingleConfig, error::GenomeError, DEPLOYER, GENOME_ROOT, PLATFORM, SINGLE_CONFIG,
+    data::{GenomeSingleConfig, TournamentConfig},
+    error::GenomeError,
+    fees::BPS,
+    DEPLOYER, GENOME_ROOT, PLATFORM, SINGLE_CONFIG,
 };
 
 pub(crate) fn handle_initialize_single(
     ctx: Context<Initialize>,
     config_params: GenomeSingleConfig,
 ) -> Result<()> {
+    require!(
+        config_params.claim_period > 0
+            && config_params.claim_period <= TournamentConfig::MAX_CLAIM_PERIOD,
+        GenomeError::InvalidClaimPeriod
+    );
+    require!(config_params.stake_lock_period > 0, GenomeError::InvalidStakeLockPeriod);
+    require!(config_params.verifier_slash_rate <= BPS, GenomeError::InvalidSlashRate);
     ctx.accounts.config.set_inner(config_params);
     ctx.accounts.config.platform_wallet = ctx.accounts.platform_wallet.key();
+    ctx.accounts.config.total_stake = 0;
     Ok(())
 }
 
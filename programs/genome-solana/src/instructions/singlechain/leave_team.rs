@@ -0,0 +1,134 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    data::{BloomFilter, Payout, Tournament, TournamentStatus},
+    error::GenomeError,
+    reward_pool::RewardPool,
+    team::Team,
+    utils::release_bloom_slots,
+    BLOOM, GENOME_ROOT, TEAM, TOURNAMENT,
+};
+
+pub fn handle_leave_team(
+    ctx: Context<LeaveTeam>,
+    tournament_id: u32,
+    captain: Pubkey,
+) -> Result<()> {
+    let tournament = &mut ctx.accounts.tournament;
+    let team = &mut ctx.accounts.team;
+    let participant = ctx.accounts.participant.key();
+
+    let was_completed = team.completed;
+    let team_deposits = team.deposits();
+    let participant_info = team.remove_participant(&participant)?;
+    if was_completed {
+        tournament.team_count -= 1;
+        tournament.entry_pool -= team_deposits;
+    }
+
+    // A pending invite which was not prepaid has nothing to refund
+    if participant_info.paid_by_captain || participant_info.accepted {
+        let reward_pool = RewardPool::new(
+            tournament,
+            ctx.bumps.tournament,
+            &ctx.accounts.mint,
+            ctx.accounts.reward_pool_ata.as_deref(),
+            &ctx.accounts.token_program,
+        )?;
+        let refund_to = if participant_info.paid_by_captain {
+            reward_pool.recipient(
+                &ctx.accounts.captain_ata,
+                ctx.accounts.captain_wallet.to_account_info(),
+            )?
+        } else {
+            reward_pool.recipient(
+                &ctx.accounts.participant_ata,
+                ctx.accounts.participant.to_account_info(),
+            )?
+        };
+        tournament.record_payout(Payout::Refund, participant_info.deposit)?;
+        reward_pool.pay(refund_to, participant_info.deposit)?;
+    }
+
+    if participant_info.accepted {
+        release_bloom_slots(
+            &mut ctx.accounts.bloom_filter,
+            ctx.accounts.participant.to_account_info(),
+            &[participant],
+        )?;
+    }
+
+    emit!(TeamLeft {
+        tournament_id,
+        captain,
+        participant,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(tournament_id: u32, captain: Pubkey)]
+pub struct LeaveTeam<'info> {
+    #[account(mut)]
+    pub participant: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GENOME_ROOT, TOURNAMENT, tournament_id.to_le_bytes().as_ref()],
+        constraint = tournament.status == TournamentStatus::New @ GenomeError::InvalidStatus,
+        bump
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    #[account(
+        mut,
+        seeds = [GENOME_ROOT, TEAM, tournament_id.to_le_bytes().as_ref(), captain.as_ref()],
+        bump
+    )]
+    pub team: Account<'info, Team>,
+
+    #[account(address = tournament.config.asset_mint @ GenomeError::InvalidToken)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = participant,
+        associated_token::token_program = token_program,
+    )]
+    pub participant_ata: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = captain,
+        associated_token::token_program = token_program,
+    )]
+    pub captain_ata: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(mut, address = captain)]
+    pub captain_wallet: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = tournament,
+        associated_token::token_program = token_program,
+    )]
+    pub reward_pool_ata: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(mut, seeds = [GENOME_ROOT, BLOOM, tournament_id.to_le_bytes().as_ref()], bump)]
+    pub bloom_filter: Box<Account<'info, BloomFilter>>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[event]
+pub struct TeamLeft {
+    pub tournament_id: u32,
+    pub captain: Pubkey,
+    pub participant: Pubkey,
+}
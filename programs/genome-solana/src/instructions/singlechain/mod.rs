@@ -3,6 +3,8 @@ use anchor_lang::{
     solana_program::{program::invoke, system_instruction},
 };
 
+use crate::{data::Tournament, error::GenomeError, team::Team, GENOME_ROOT, TEAM};
+
 pub(crate) mod initialize;
 pub(crate) use initialize::*;
 
@@ -21,18 +23,72 @@ pub(crate) use revoke_role::*;
 pub(crate) mod create_tournament;
 pub(crate) use create_tournament::*;
 
+pub(crate) mod update_tournament;
+pub(crate) use update_tournament::*;
+
+pub(crate) mod update_tournament_metadata;
+pub(crate) use update_tournament_metadata::*;
+
 pub(crate) mod register_tournament;
 pub(crate) use register_tournament::*;
 
+pub(crate) mod add_sponsorship;
+pub(crate) use add_sponsorship::*;
+
+pub(crate) mod add_bonus_pool;
+pub(crate) use add_bonus_pool::*;
+
+pub(crate) mod set_allowlist;
+pub(crate) use set_allowlist::*;
+
+pub(crate) mod accept_invite;
+pub(crate) use accept_invite::*;
+
+pub(crate) mod expire_invites;
+pub(crate) use expire_invites::*;
+
+pub(crate) mod leave_team;
+pub(crate) use leave_team::*;
+
+pub(crate) mod disband_team;
+pub(crate) use disband_team::*;
+
 pub(crate) mod set_bloom_precision;
 pub(crate) use set_bloom_precision::*;
 
+pub(crate) mod set_cancel_penalty;
+pub(crate) use set_cancel_penalty::*;
+
+pub(crate) mod set_claim_period;
+pub(crate) use set_claim_period::*;
+
+pub(crate) mod set_slash_rate;
+pub(crate) use set_slash_rate::*;
+
 pub(crate) mod start_tournament;
 pub(crate) use start_tournament::*;
 
 pub(crate) mod cancel_tournament;
 pub(crate) use cancel_tournament::*;
 
+pub(crate) mod organizer_cancel;
+pub(crate) use organizer_cancel::*;
+
+pub(crate) mod expire_tournament;
+pub(crate) use expire_tournament::*;
+
+pub(crate) mod create_bracket;
+pub(crate) use create_bracket::*;
+
+pub(crate) mod report_match;
+pub(crate) use report_match::*;
+
+pub(crate) mod create_league;
+pub(crate) use create_league::*;
+
+pub(crate) mod report_league_match;
+pub(crate) use report_league_match::*;
+
 pub(crate) mod finish_tournament;
 pub(crate) use finish_tournament::*;
 
@@ -42,12 +98,42 @@ pub(crate) use claim_refund::*;
 pub(crate) mod claim_role_fund;
 pub(crate) use claim_role_fund::*;
 
+pub(crate) mod stake;
+pub(crate) use stake::*;
+
+pub(crate) mod unstake;
+pub(crate) use unstake::*;
+
+pub(crate) mod settle_verifier;
+pub(crate) use settle_verifier::*;
+
 pub(crate) mod claim_sponros_refund;
 pub(crate) use claim_sponros_refund::*;
 
 pub(crate) mod claim_reward;
 pub(crate) use claim_reward::*;
 
+pub(crate) mod claim_badge;
+pub(crate) use claim_badge::*;
+
+pub(crate) mod sweep_unclaimed;
+pub(crate) use sweep_unclaimed::*;
+
+pub(crate) mod close_team;
+pub(crate) use close_team::*;
+
+pub(crate) mod close_sponsorship;
+pub(crate) use close_sponsorship::*;
+
+pub(crate) mod close_tournament;
+pub(crate) use close_tournament::*;
+
+pub(crate) mod close_bonus_pool;
+pub(crate) use close_bonus_pool::*;
+
+pub(crate) mod distribute_rewards;
+pub(crate) use distribute_rewards::*;
+
 pub(crate) mod withdraw;
 pub(crate) use withdraw::*;
 
@@ -79,3 +165,33 @@ pub(crate) fn system_transfer<'a>(
     invoke(&ix, &[from.clone(), to.clone()])?;
     Ok(())
 }
+
+/// Collects the captains of every completed team of the tournament. The team PDAs are
+/// passed in `remaining_accounts` in any order, the result is sorted by captain key.
+pub(crate) fn completed_team_captains<'info>(
+    program_id: &Pubkey,
+    tournament: &Tournament,
+    team_accounts: &'info [AccountInfo<'info>],
+) -> Result<Vec<Pubkey>> {
+    require!(team_accounts.len() == tournament.team_count as usize, GenomeError::InvalidTeam);
+
+    let mut captains = Vec::with_capacity(team_accounts.len());
+    for account in team_accounts {
+        let team = Account::<Team>::try_from(account)?;
+        let (team_pda, _) = Pubkey::find_program_address(
+            &[
+                GENOME_ROOT,
+                TEAM,
+                &tournament.id.to_le_bytes(),
+                team.captain.as_ref(),
+            ],
+            program_id,
+        );
+        require!(team_pda == account.key() && team.completed, GenomeError::InvalidTeam);
+        require!(!captains.contains(&team.captain), GenomeError::InvalidTeam);
+        captains.push(team.captain);
+    }
+    captains.sort();
+
+    Ok(captains)
+}
@@ -0,0 +1,129 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    data::{GenomeSingleConfig, Payout, RoleInfo, Sponsorship, Tournament, TournamentStatus},
+    error::GenomeError,
+    fees::bps_of,
+    instructions::SponsorRefunded,
+    reward_pool::RewardPool,
+    Role, GENOME_ROOT, ROLE, SINGLE_CONFIG, SPONSORSHIP, TOURNAMENT,
+};
+
+/// Cancels the tournament on behalf of the organizer. Without registrations it's allowed
+/// anytime before the tournament starts, otherwise only before `expiration_time`, and the
+/// registered participants claim their refunds as for any canceled tournament. The main
+/// sponsor's contribution goes straight back to them, other sponsors claim their refunds,
+/// and the organizer gets the platform fee back minus the cancel penalty.
+pub fn handle_organizer_cancel(ctx: Context<OrganizerCancel>, tournament_id: u32) -> Result<()> {
+    let clock = Clock::get()?;
+    let config = &ctx.accounts.config;
+    let tournament = &mut ctx.accounts.tournament;
+
+    require!(
+        tournament.registered_teams == 0
+            || (clock.unix_timestamp as u64) < tournament.config.expiration_time,
+        GenomeError::RegistrationsExist
+    );
+
+    let sponsorship = &mut ctx.accounts.sponsorship;
+    let sponsor_refund = sponsorship.amount;
+    if sponsor_refund > 0 {
+        let reward_pool = RewardPool::new(
+            tournament,
+            ctx.bumps.tournament,
+            &ctx.accounts.asset_mint,
+            ctx.accounts.reward_pool_ata.as_deref(),
+            &ctx.accounts.token_program,
+        )?;
+        let refund_to = reward_pool
+            .recipient(&ctx.accounts.sponsor_ata, ctx.accounts.sponsor.to_account_info())?;
+        tournament.record_payout(Payout::Refund, sponsor_refund)?;
+        reward_pool.pay(refund_to, sponsor_refund)?;
+
+        emit!(SponsorRefunded {
+            tournament_id,
+            sponsor: sponsorship.sponsor,
+            amount: sponsor_refund
+        });
+    }
+    sponsorship.refunded = true;
+
+    let penalty = bps_of(config.platform_fee, config.organizer_cancel_penalty)?;
+    ctx.accounts.role_info_org.claim += config.platform_fee - penalty;
+    tournament.settle(
+        TournamentStatus::Canceled,
+        config.claim_period,
+        clock.unix_timestamp as u64,
+    )?;
+
+    emit!(OrganizerCanceled {
+        tournament_id,
+        sponsor_refund,
+        penalty
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(tournament_id: u32)]
+pub struct OrganizerCancel<'info> {
+    #[account(address = tournament.organizer @ GenomeError::NotAllowed)]
+    pub organizer: Signer<'info>,
+
+    #[account(mut, address = tournament.config.sponsor @ GenomeError::NotAllowed)]
+    pub sponsor: SystemAccount<'info>,
+
+    #[account(seeds = [GENOME_ROOT, SINGLE_CONFIG], bump)]
+    pub config: Account<'info, GenomeSingleConfig>,
+
+    #[account(
+        mut,
+        seeds = [GENOME_ROOT, TOURNAMENT, tournament_id.to_le_bytes().as_ref()],
+        constraint = tournament.status == TournamentStatus::New @ GenomeError::InvalidStatus,
+        bump
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    #[account(
+        mut,
+        seeds = [GENOME_ROOT, ROLE, tournament.organizer.as_ref()],
+        bump,
+        constraint = role_info_org.roles.contains(&Role::Organizer) @ GenomeError::NotAllowed,
+    )]
+    pub role_info_org: Account<'info, RoleInfo>,
+
+    #[account(
+        mut,
+        seeds = [GENOME_ROOT, SPONSORSHIP, tournament_id.to_le_bytes().as_ref(), sponsor.key().as_ref()],
+        bump
+    )]
+    pub sponsorship: Account<'info, Sponsorship>,
+
+    #[account(constraint = asset_mint.key() == tournament.config.asset_mint @ GenomeError::InvalidNome)]
+    pub asset_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = asset_mint,
+        associated_token::authority = sponsor,
+    )]
+    pub sponsor_ata: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        mut,
+        associated_token::mint = asset_mint,
+        associated_token::authority = tournament,
+    )]
+    pub reward_pool_ata: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[event]
+pub struct OrganizerCanceled {
+    pub tournament_id: u32,
+    pub sponsor_refund: u64,
+    pub penalty: u64,
+}
@@ -1,13 +1,13 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token_interface::{
-    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
-};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 use growable_bloom_filter::GrowableBloom as Bloom;
 
 use crate::{
     data::{BloomFilter, GenomeSingleConfig, Tournament, TournamentStatus},
     error::GenomeError,
+    reward_pool::RewardPool,
     team::{ParticipantInfo, Team},
+    utils::{check_allowlist, take_bloom_slot},
     BLOOM, GENOME_ROOT, SINGLE_CONFIG, TEAM, TOURNAMENT,
 };
 
@@ -18,70 +18,88 @@ pub fn handle_register_tournament(
     let tournament = &mut ctx.accounts.tournament;
     require!(tournament.status == TournamentStatus::New, GenomeError::InvalidStatus);
 
+    let reward_pool = RewardPool::new(
+        tournament,
+        ctx.bumps.tournament,
+        &ctx.accounts.mint,
+        ctx.accounts.reward_pool_ata.as_deref(),
+        &ctx.accounts.token_program,
+    )?;
+    let participant_ata = ctx.accounts.participant_ata.as_ref().map(|ata| ata.to_account_info());
     let team = &mut ctx.accounts.team;
     let bloom_filter = &mut ctx.accounts.bloom_filter;
 
     let mut bloom: Bloom =
         bincode::deserialize(&bloom_filter.data).expect("Error deserialize Bloom");
 
-    bloom_check(&register_params, &mut bloom)?;
+    let mut participants = vec![register_params.participant];
+    if register_params.participant == register_params.captain {
+        participants.extend_from_slice(&register_params.teammates);
+    }
+    check_allowlist(&tournament.config.allowlist_root, &participants, &register_params.proofs)?;
+
+    take_bloom_slot(&register_params.participant, &mut bloom, &mut bloom_filter.released)?;
 
     if register_params.participant == register_params.captain {
-        **team = Team::new(register_params.participant, tournament.config.team_size);
-
-        let cpi_ctx = CpiContext::new(
-            ctx.accounts.token_program.to_account_info(),
-            TransferChecked {
-                from: ctx.accounts.participant_ata.to_account_info(),
-                to: ctx.accounts.reward_pool_ata.to_account_info(),
-                mint: ctx.accounts.mint.to_account_info(),
-                authority: ctx.accounts.participant.to_account_info(),
-            },
+        require!(
+            tournament.registered_teams < tournament.config.max_teams as u32,
+            GenomeError::MaxTeamsExceeded
+        );
+        if !register_params.teammates.is_empty() {
+            let clock = Clock::get()?;
+            require!(
+                register_params.invite_expiration > clock.unix_timestamp as u64
+                    && register_params.invite_expiration <= tournament.config.expiration_time,
+                GenomeError::InvalidInviteExpiration
+            );
+        }
+
+        tournament.registered_teams += 1;
+        **team = Team::new(
+            register_params.participant,
+            tournament.config.team_size,
+            register_params.invite_expiration,
         );
 
-        let mut all_participants = vec![register_params.participant];
-        all_participants.extend(register_params.teammates);
+        let mut paid_entries = 1;
+        if register_params.prepay {
+            paid_entries += register_params.teammates.len() as u64;
+        }
 
-        transfer_checked(
-            cpi_ctx,
-            all_participants.len() as u64 * tournament.config.entry_fee,
-            ctx.accounts.mint.decimals,
+        let deposit = reward_pool.deposit(
+            participant_ata,
+            ctx.accounts.participant.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+            paid_entries * tournament.config.entry_fee,
         )?;
+        tournament.record_entry_fee(deposit);
 
-        team.add_participants_by_captain(all_participants)?;
+        team.add_participants_by_captain(
+            register_params.teammates,
+            register_params.prepay,
+            deposit,
+        )?;
     } else {
-        let cpi_ctx = CpiContext::new(
-            ctx.accounts.token_program.to_account_info(),
-            TransferChecked {
-                from: ctx.accounts.participant_ata.to_account_info(),
-                to: ctx.accounts.reward_pool_ata.to_account_info(),
-                mint: ctx.accounts.mint.to_account_info(),
-                authority: ctx.accounts.participant.to_account_info(),
-            },
-        );
-        transfer_checked(cpi_ctx, tournament.config.entry_fee, ctx.accounts.mint.decimals)?;
+        let deposit = reward_pool.deposit(
+            participant_ata,
+            ctx.accounts.participant.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+            tournament.config.entry_fee,
+        )?;
+        tournament.record_entry_fee(deposit);
 
-        team.add_participant(register_params.participant)?;
+        team.add_participant(register_params.participant, deposit)?;
     }
 
-    if team.completed == true {
+    if team.completed {
         tournament.team_count += 1;
+        tournament.entry_pool += team.deposits();
     }
     bloom_filter.data = bincode::serialize(&bloom).expect("Error serialize Bloom");
 
     Ok(())
 }
 
-fn bloom_check(register_params: &RegisterParams, bloom: &mut Bloom) -> Result<()> {
-    require!(bloom.insert(register_params.participant), GenomeError::AlreadyRegistered);
-
-    for teammate in &register_params.teammates {
-        require!(bloom.insert(teammate), GenomeError::AlreadyRegistered);
-    }
-
-    Ok(())
-}
-
 #[derive(Accounts)]
 #[instruction(register_params: RegisterParams)]
 pub struct RegisterParticipant<'info> {
@@ -116,7 +134,7 @@ pub struct RegisterParticipant<'info> {
         associated_token::authority = participant,
         associated_token::token_program = token_program,
     )]
-    participant_ata: InterfaceAccount<'info, TokenAccount>,
+    participant_ata: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
 
     #[account(
         mut,
@@ -124,7 +142,7 @@ pub struct RegisterParticipant<'info> {
         associated_token::authority = tournament,
         associated_token::token_program = token_program,
     )]
-    reward_pool_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+    reward_pool_ata: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
 
     #[account(mut, seeds = [GENOME_ROOT, BLOOM, register_params.tournament_id.to_le_bytes().as_ref()], bump)]
     bloom_filter: Box<Account<'info, BloomFilter>>,
@@ -139,10 +157,7 @@ pub struct RegisterParams {
     participant: Pubkey,
     captain: Pubkey,
     teammates: Vec<Pubkey>,
-}
-
-#[event]
-pub struct ParticipantRegistered {
-    participant: Pubkey,
-    captain: Pubkey,
+    prepay: bool,
+    invite_expiration: u64,
+    proofs: Vec<Vec<[u8; 32]>>,
 }
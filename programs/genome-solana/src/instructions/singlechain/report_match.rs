@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    bracket::Bracket,
+    data::{GenomeSingleConfig, RoleInfo, RoleList, Tournament, TournamentStatus},
+    error::GenomeError,
+    fees::reaches_rate,
+    Role, BRACKET, GENOME_ROOT, ROLE, SINGLE_CONFIG, TOURNAMENT,
+};
+
+pub fn handle_report_match(
+    ctx: Context<ReportMatch>,
+    tournament_id: u32,
+    match_index: u16,
+    winner: Pubkey,
+) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let role_info = &mut ctx.accounts.role_info;
+    let bracket = &mut ctx.accounts.bracket;
+    let verifier_pk = ctx.accounts.verifier.key();
+
+    let verifier_index = ctx
+        .accounts
+        .verifier_list
+        .accounts
+        .iter()
+        .position(|&v| v == verifier_pk)
+        .ok_or(GenomeError::RoleNotFound)?;
+
+    // Like start votes, match votes are paid up front: `vote` rejects a second vote of the same
+    // verifier on a match, and match votes carry no stake to slash, so there's nothing to settle.
+    let votes = bracket.vote(match_index as usize, winner, verifier_index)?;
+    role_info.claim += config.verifier_fee;
+
+    let total = ctx.accounts.verifier_list.accounts.len() as u64;
+
+    if reaches_rate(votes, total, config.consensus_rate) {
+        bracket.advance(match_index as usize, winner);
+        emit!(MatchFinished {
+            tournament_id,
+            match_index,
+            winner
+        });
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(tournament_id: u32)]
+pub struct ReportMatch<'info> {
+    pub verifier: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GENOME_ROOT, ROLE, verifier.key().as_ref()],
+        bump,
+        constraint = role_info.roles.contains(&Role::Verifier) @ GenomeError::NotAllowed,
+    )]
+    pub role_info: Account<'info, RoleInfo>,
+
+    #[account(seeds = [GENOME_ROOT, ROLE, Role::Verifier.to_seed()], bump)]
+    pub verifier_list: Account<'info, RoleList>,
+
+    #[account(seeds = [GENOME_ROOT, SINGLE_CONFIG], bump)]
+    pub config: Account<'info, GenomeSingleConfig>,
+
+    #[account(
+        seeds = [GENOME_ROOT, TOURNAMENT, tournament_id.to_le_bytes().as_ref()],
+        constraint = tournament.status == TournamentStatus::Started @ GenomeError::InvalidStatus,
+        bump
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    #[account(mut, seeds = [GENOME_ROOT, BRACKET, tournament_id.to_le_bytes().as_ref()], bump)]
+    pub bracket: Box<Account<'info, Bracket>>,
+}
+
+#[event]
+pub struct MatchFinished {
+    pub tournament_id: u32,
+    pub match_index: u16,
+    pub winner: Pubkey,
+}
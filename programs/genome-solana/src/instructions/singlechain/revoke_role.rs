@@ -1,9 +1,9 @@
 use anchor_lang::{prelude::*, solana_program::pubkey::PUBKEY_BYTES};
 
 use crate::{
-    data::{GenomeSingleConfig, Role, RoleInfo, RoleList},
+    data::{GenomeSingleConfig, Role, RoleInfo, RoleList, VerifierStake},
     error::GenomeError,
-    realloc, GENOME_ROOT, ROLE, SINGLE_CONFIG,
+    realloc, GENOME_ROOT, ROLE, SINGLE_CONFIG, STAKE,
 };
 
 pub(crate) fn handle_revoke_role(ctx: Context<RevokeRole>, role: Role) -> Result<()> {
@@ -31,6 +31,17 @@ pub(crate) fn handle_revoke_role(ctx: Context<RevokeRole>, role: Role) -> Result
     role_list.accounts.remove(index_role_list);
     ctx.accounts.role_info.roles.remove(index_role_info);
 
+    // A revoked verifier can't vote anymore, so their stake leaves the consensus weight
+    if role == Role::Verifier && !ctx.accounts.stake.data_is_empty() {
+        let mut data = ctx.accounts.stake.try_borrow_mut_data()?;
+        let mut stake = VerifierStake::try_deserialize(&mut &data[..])?;
+        if stake.active {
+            ctx.accounts.config.total_stake -= stake.amount;
+            stake.active = false;
+            stake.try_serialize(&mut &mut data[..])?;
+        }
+    }
+
     Ok(())
 }
 
@@ -48,6 +59,10 @@ pub(crate) struct RevokeRole<'info> {
     #[account(mut, seeds = [GENOME_ROOT, ROLE, user.key().as_ref()], bump)]
     role_info: Account<'info, RoleInfo>,
 
+    /// CHECK: the user's stake PDA, which only exists once they staked as a verifier
+    #[account(mut, seeds = [GENOME_ROOT, STAKE, user.key().as_ref()], bump)]
+    stake: UncheckedAccount<'info>,
+
     #[account(
         mut,
         seeds = [GENOME_ROOT, ROLE, role.to_seed()],
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    data::{Tournament, TournamentStatus},
+    error::GenomeError,
+    GENOME_ROOT, TOURNAMENT,
+};
+
+pub(crate) fn handle_set_allowlist(
+    ctx: Context<SetAllowlist>,
+    tournament_id: u32,
+    allowlist_root: Option<[u8; 32]>,
+) -> Result<()> {
+    ctx.accounts.tournament.config.allowlist_root = allowlist_root;
+
+    emit!(AllowlistUpdated {
+        tournament_id,
+        allowlist_root
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(tournament_id: u32)]
+pub(crate) struct SetAllowlist<'info> {
+    #[account(address = tournament.organizer @ GenomeError::NotAllowed)]
+    pub organizer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GENOME_ROOT, TOURNAMENT, tournament_id.to_le_bytes().as_ref()],
+        constraint = tournament.status == TournamentStatus::New @ GenomeError::InvalidStatus,
+        bump
+    )]
+    pub tournament: Account<'info, Tournament>,
+}
+
+#[event]
+pub(crate) struct AllowlistUpdated {
+    pub tournament_id: u32,
+    pub allowlist_root: Option<[u8; 32]>,
+}
@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+use crate::{data::GenomeSingleConfig, error::GenomeError, fees::BPS, GENOME_ROOT, SINGLE_CONFIG};
+
+pub(crate) fn handle_set_cancel_penalty(
+    ctx: Context<SetCancelPenalty>,
+    penalty: u64,
+) -> Result<()> {
+    require!(penalty <= BPS, GenomeError::InvalidPenalty);
+
+    let config = &mut ctx.accounts.config;
+    config.organizer_cancel_penalty = penalty;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub(crate) struct SetCancelPenalty<'info> {
+    #[account(mut, address = config.admin @ GenomeError::NotAllowed)]
+    pub admin: Signer<'info>,
+
+    #[account(mut, seeds = [GENOME_ROOT, SINGLE_CONFIG], bump)]
+    pub config: Account<'info, GenomeSingleConfig>,
+}
@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    data::{GenomeSingleConfig, TournamentConfig},
+    error::GenomeError,
+    GENOME_ROOT, SINGLE_CONFIG,
+};
+
+pub(crate) fn handle_set_claim_period(
+    ctx: Context<SetClaimPeriod>,
+    claim_period: u64,
+) -> Result<()> {
+    require!(
+        claim_period > 0 && claim_period <= TournamentConfig::MAX_CLAIM_PERIOD,
+        GenomeError::InvalidClaimPeriod
+    );
+
+    let config = &mut ctx.accounts.config;
+    config.claim_period = claim_period;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub(crate) struct SetClaimPeriod<'info> {
+    #[account(mut, address = config.admin @ GenomeError::NotAllowed)]
+    pub admin: Signer<'info>,
+
+    #[account(mut, seeds = [GENOME_ROOT, SINGLE_CONFIG], bump)]
+    pub config: Account<'info, GenomeSingleConfig>,
+}
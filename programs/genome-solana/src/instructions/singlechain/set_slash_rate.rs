@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+
+use crate::{data::GenomeSingleConfig, error::GenomeError, fees::BPS, GENOME_ROOT, SINGLE_CONFIG};
+
+pub(crate) fn handle_set_slash_rate(ctx: Context<SetSlashRate>, slash_rate: u64) -> Result<()> {
+    require!(slash_rate <= BPS, GenomeError::InvalidSlashRate);
+
+    let config = &mut ctx.accounts.config;
+    config.verifier_slash_rate = slash_rate;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub(crate) struct SetSlashRate<'info> {
+    #[account(mut, address = config.admin @ GenomeError::NotAllowed)]
+    pub admin: Signer<'info>,
+
+    #[account(mut, seeds = [GENOME_ROOT, SINGLE_CONFIG], bump)]
+    pub config: Account<'info, GenomeSingleConfig>,
+}
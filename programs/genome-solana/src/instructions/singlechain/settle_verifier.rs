@@ -0,0 +1,145 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::{
+    data::{
+        FinishMetaData, GenomeSingleConfig, RoleInfo, Tournament, TournamentStatus, VerifierStake,
+    },
+    error::GenomeError,
+    fees::bps_of,
+    FINISH, GENOME_ROOT, PLATFORM, ROLE, SINGLE_CONFIG, STAKE, TOURNAMENT,
+};
+
+/// Settles the finish vote of a verifier once the tournament is finished. A verifier who
+/// backed the winning captain is paid the verifier fee. Otherwise the fee is withheld and
+/// `verifier_slash_rate` of the verifier's stake is slashed into the platform wallet. Votes on
+/// a tournament that expired without a finish are settled with neither a fee nor a slash.
+pub fn handle_settle_verifier(
+    ctx: Context<SettleVerifier>,
+    tournament_id: u32,
+    verifier: Pubkey,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let finish_meta = &mut ctx.accounts.finish_meta_data;
+
+    let vote = finish_meta.vote_of(&verifier).ok_or(GenomeError::VoteNotFound)?;
+    require!((finish_meta.settled >> vote) & 1 == 0, GenomeError::AlreadySettled);
+    finish_meta.settled |= 1 << vote;
+    ctx.accounts.stake.unsettled_votes -= 1;
+
+    if ctx.accounts.tournament.status == TournamentStatus::Canceled {
+        emit!(VerifierSettled {
+            tournament_id,
+            verifier,
+            fee: 0,
+            slashed: 0
+        });
+        return Ok(());
+    }
+
+    if finish_meta.backed_winner(vote) {
+        ctx.accounts.role_info.claim += config.verifier_fee;
+        emit!(VerifierSettled {
+            tournament_id,
+            verifier,
+            fee: config.verifier_fee,
+            slashed: 0
+        });
+        return Ok(());
+    }
+
+    let stake = &mut ctx.accounts.stake;
+    let slashed = bps_of(stake.amount, config.verifier_slash_rate)?;
+    stake.amount -= slashed;
+    if stake.active {
+        config.total_stake -= slashed;
+    }
+
+    if slashed > 0 {
+        let stake_seeds: &[&[u8]] = &[GENOME_ROOT, STAKE, verifier.as_ref(), &[ctx.bumps.stake]];
+        let signer = &[stake_seeds];
+
+        let accounts = TransferChecked {
+            from: ctx.accounts.stake_ata.to_account_info(),
+            to: ctx.accounts.platform_ata.to_account_info(),
+            mint: ctx.accounts.nome_mint.to_account_info(),
+            authority: ctx.accounts.stake.to_account_info(),
+        };
+        let cpi = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            accounts,
+            signer,
+        );
+        transfer_checked(cpi, slashed, ctx.accounts.nome_mint.decimals)?;
+    }
+
+    emit!(VerifierSettled {
+        tournament_id,
+        verifier,
+        fee: 0,
+        slashed
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(tournament_id: u32, verifier: Pubkey)]
+pub struct SettleVerifier<'info> {
+    pub caller: Signer<'info>,
+
+    #[account(mut, seeds = [GENOME_ROOT, SINGLE_CONFIG], bump)]
+    pub config: Account<'info, GenomeSingleConfig>,
+
+    #[account(
+        seeds = [GENOME_ROOT, TOURNAMENT, tournament_id.to_le_bytes().as_ref()],
+        constraint = tournament.status == TournamentStatus::Finished
+            || tournament.status == TournamentStatus::Canceled @ GenomeError::InvalidStatus,
+        bump
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    #[account(mut, seeds = [GENOME_ROOT, FINISH, tournament_id.to_le_bytes().as_ref()], bump)]
+    pub finish_meta_data: Box<Account<'info, FinishMetaData>>,
+
+    #[account(mut, seeds = [GENOME_ROOT, ROLE, verifier.as_ref()], bump)]
+    pub role_info: Account<'info, RoleInfo>,
+
+    #[account(mut, seeds = [GENOME_ROOT, STAKE, verifier.as_ref()], bump)]
+    pub stake: Account<'info, VerifierStake>,
+
+    /// CHECKED
+    #[account(seeds = [GENOME_ROOT, PLATFORM], bump)]
+    pub platform_wallet: UncheckedAccount<'info>,
+
+    #[account(constraint = nome_mint.key() == config.nome_mint @ GenomeError::InvalidNome)]
+    pub nome_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = nome_mint,
+        associated_token::authority = stake,
+        associated_token::token_program = token_program,
+    )]
+    pub stake_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = nome_mint,
+        associated_token::authority = platform_wallet,
+        associated_token::token_program = token_program,
+    )]
+    pub platform_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[event]
+pub struct VerifierSettled {
+    pub tournament_id: u32,
+    pub verifier: Pubkey,
+    pub fee: u64,
+    pub slashed: u64,
+}
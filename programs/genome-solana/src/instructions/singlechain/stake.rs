@@ -0,0 +1,104 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked},
+};
+
+use crate::{
+    data::{GenomeSingleConfig, RoleInfo, VerifierStake},
+    error::GenomeError,
+    Role, GENOME_ROOT, ROLE, SINGLE_CONFIG, STAKE,
+};
+
+pub fn handle_stake(ctx: Context<Stake>, amount: u64) -> Result<()> {
+    require!(amount > 0, GenomeError::InvalidStake);
+
+    let before = ctx.accounts.stake_ata.amount;
+    let accounts = TransferChecked {
+        from: ctx.accounts.verifier_ata.to_account_info(),
+        to: ctx.accounts.stake_ata.to_account_info(),
+        mint: ctx.accounts.nome_mint.to_account_info(),
+        authority: ctx.accounts.verifier.to_account_info(),
+    };
+    let cpi = CpiContext::new(ctx.accounts.token_program.to_account_info(), accounts);
+    transfer_checked(cpi, amount, ctx.accounts.nome_mint.decimals)?;
+
+    ctx.accounts.stake_ata.reload()?;
+    let amount = ctx.accounts.stake_ata.amount - before;
+
+    let verifier = ctx.accounts.verifier.key();
+    let stake = &mut ctx.accounts.stake;
+    stake.verifier = verifier;
+    stake.amount = stake.amount.checked_add(amount).ok_or(GenomeError::MathOverflow)?;
+    // A stake taken out of the total by a revoked role comes back in full
+    let counted = if stake.active { amount } else { stake.amount };
+    stake.active = true;
+
+    let config = &mut ctx.accounts.config;
+    config.total_stake =
+        config.total_stake.checked_add(counted).ok_or(GenomeError::MathOverflow)?;
+
+    emit!(VerifierStaked {
+        verifier,
+        amount,
+        stake: stake.amount
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct Stake<'info> {
+    #[account(mut)]
+    pub verifier: Signer<'info>,
+
+    #[account(
+        seeds = [GENOME_ROOT, ROLE, verifier.key().as_ref()],
+        bump,
+        constraint = role_info.roles.contains(&Role::Verifier) @ GenomeError::NotAllowed,
+    )]
+    pub role_info: Account<'info, RoleInfo>,
+
+    #[account(mut, seeds = [GENOME_ROOT, SINGLE_CONFIG], bump)]
+    pub config: Account<'info, GenomeSingleConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = verifier,
+        space = VerifierStake::DISCRIMINATOR.len() + VerifierStake::INIT_SPACE,
+        seeds = [GENOME_ROOT, STAKE, verifier.key().as_ref()],
+        bump
+    )]
+    pub stake: Account<'info, VerifierStake>,
+
+    #[account(constraint = nome_mint.key() == config.nome_mint @ GenomeError::InvalidNome)]
+    pub nome_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = nome_mint,
+        associated_token::authority = verifier,
+        associated_token::token_program = token_program,
+    )]
+    pub verifier_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = verifier,
+        associated_token::mint = nome_mint,
+        associated_token::authority = stake,
+        associated_token::token_program = token_program,
+    )]
+    pub stake_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[event]
+pub struct VerifierStaked {
+    pub verifier: Pubkey,
+    pub amount: u64,
+    pub stake: u64,
+}
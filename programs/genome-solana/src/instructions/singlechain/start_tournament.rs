@@ -1,7 +1,11 @@
 use crate::{
-    data::{Consensus, GenomeSingleConfig, RoleInfo, RoleList, Tournament, TournamentStatus},
+    data::{
+        Consensus, GenomeSingleConfig, RoleInfo, RoleList, Tournament, TournamentStatus,
+        VerifierStake,
+    },
     error::GenomeError,
-    Role, CONSENSUS, GENOME_ROOT, ROLE, SINGLE_CONFIG, TOURNAMENT,
+    fees::reaches_rate,
+    Role, CONSENSUS, GENOME_ROOT, ROLE, SINGLE_CONFIG, STAKE, TOURNAMENT,
 };
 use anchor_lang::prelude::*;
 
@@ -13,6 +17,10 @@ pub fn handle_start_tournament(ctx: Context<StartTournament>, tournament_id: u32
     let verifier_pk = ctx.accounts.verifier.key();
 
     require!(tournament.team_count != 0, GenomeError::NoCompletedTeams);
+    require!(
+        tournament.team_count >= tournament.config.min_teams as u32,
+        GenomeError::NotEnoughTeams
+    );
 
     let verifier_index = ctx
         .accounts
@@ -24,13 +32,14 @@ pub fn handle_start_tournament(ctx: Context<StartTournament>, tournament_id: u32
 
     require!((consensus.start_votes >> verifier_index) & 1 == 0, GenomeError::AlreadyVoted);
 
+    let now = Clock::get()?.unix_timestamp as u64;
+    let weight = ctx.accounts.stake.vote_weight(now, config.stake_lock_period)?;
+
     consensus.start_votes |= 1 << verifier_index;
+    consensus.start_weight += weight;
     role_info.claim += config.verifier_fee;
 
-    let votes = consensus.start_votes.count_ones() as u64;
-    let total = ctx.accounts.verifier_list.accounts.len() as u64;
-
-    if votes * 10000 >= total * config.consensus_rate {
+    if reaches_rate(consensus.start_weight, config.total_stake, config.consensus_rate) {
         tournament.status = TournamentStatus::Started;
         emit!(TournamentStarted { tournament_id });
     }
@@ -52,6 +61,9 @@ pub struct StartTournament<'info> {
     )]
     pub role_info: Account<'info, RoleInfo>,
 
+    #[account(mut, seeds = [GENOME_ROOT, STAKE, verifier.key().as_ref()], bump)]
+    pub stake: Account<'info, VerifierStake>,
+
     #[account(
         seeds = [GENOME_ROOT, ROLE, Role::Verifier.to_seed()],
         bump,
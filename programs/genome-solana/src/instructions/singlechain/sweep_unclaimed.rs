@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{
+    data::{Payout, Tournament, TournamentStatus},
+    error::GenomeError,
+    reward_pool::RewardPool,
+    GENOME_ROOT, PLATFORM, TOURNAMENT,
+};
+
+/// Moves whatever is left in the reward pool after the claim period to the platform wallet.
+/// Anything sent to the pool outside of the escrow ledger is swept along with it.
+pub fn handle_sweep_unclaimed(ctx: Context<SweepUnclaimed>, tournament_id: u32) -> Result<()> {
+    let tournament = &mut ctx.accounts.tournament;
+    let now = Clock::get()?.unix_timestamp as u64;
+    require!(!tournament.claims_open(now), GenomeError::ClaimPeriodNotOver);
+
+    let reward_pool = RewardPool::new(
+        tournament,
+        ctx.bumps.tournament,
+        &ctx.accounts.asset_mint,
+        ctx.accounts.reward_pool_ata.as_deref(),
+        &ctx.accounts.token_program,
+    )?;
+    let amount = reward_pool.balance()?;
+    require!(amount > 0, GenomeError::InsufficientFunds);
+    let escrowed = tournament.escrow_balance()?;
+    tournament.record_payout(Payout::Unclaimed, escrowed)?;
+
+    let sweep_to = reward_pool
+        .recipient(&ctx.accounts.platform_ata, ctx.accounts.platform_wallet.to_account_info())?;
+    reward_pool.pay(sweep_to, amount)?;
+
+    emit!(UnclaimedSwept {
+        tournament_id,
+        amount
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(tournament_id: u32)]
+pub struct SweepUnclaimed<'info> {
+    #[account(mut)]
+    pub caller: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GENOME_ROOT, TOURNAMENT, tournament_id.to_le_bytes().as_ref()],
+        constraint = tournament.status == TournamentStatus::Finished
+            || tournament.status == TournamentStatus::Canceled @ GenomeError::InvalidStatus,
+        bump
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// CHECK: platform wallet PDA, the token account authority or the SOL recipient
+    #[account(mut, seeds = [GENOME_ROOT, PLATFORM], bump)]
+    pub platform_wallet: UncheckedAccount<'info>,
+
+    #[account(constraint = asset_mint.key() == tournament.config.asset_mint @ GenomeError::InvalidNome)]
+    pub asset_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        init_if_needed,
+        payer = caller,
+        associated_token::mint = asset_mint,
+        associated_token::authority = platform_wallet,
+        associated_token::token_program = token_program,
+    )]
+    pub platform_ata: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        mut,
+        associated_token::mint = asset_mint,
+        associated_token::authority = tournament,
+    )]
+    pub reward_pool_ata: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[event]
+pub struct UnclaimedSwept {
+    pub tournament_id: u32,
+    pub amount: u64,
+}
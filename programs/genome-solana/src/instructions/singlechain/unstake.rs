@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::{
+    data::{GenomeSingleConfig, VerifierStake},
+    error::GenomeError,
+    GENOME_ROOT, SINGLE_CONFIG, STAKE,
+};
+
+pub fn handle_unstake(ctx: Context<Unstake>, amount: u64) -> Result<()> {
+    require!(amount > 0, GenomeError::InvalidStake);
+
+    let now = Clock::get()?.unix_timestamp as u64;
+    let stake = &mut ctx.accounts.stake;
+    require!(now >= stake.locked_until, GenomeError::StakeLocked);
+    require!(stake.unsettled_votes == 0, GenomeError::UnsettledVotes);
+    require!(stake.amount >= amount, GenomeError::InsufficientFunds);
+    stake.amount -= amount;
+    if stake.active {
+        ctx.accounts.config.total_stake -= amount;
+    }
+
+    let verifier = ctx.accounts.verifier.key();
+    let stake_seeds: &[&[u8]] = &[GENOME_ROOT, STAKE, verifier.as_ref(), &[ctx.bumps.stake]];
+    let signer = &[stake_seeds];
+
+    let accounts = TransferChecked {
+        from: ctx.accounts.stake_ata.to_account_info(),
+        to: ctx.accounts.verifier_ata.to_account_info(),
+        mint: ctx.accounts.nome_mint.to_account_info(),
+        authority: ctx.accounts.stake.to_account_info(),
+    };
+    let cpi =
+        CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), accounts, signer);
+    transfer_checked(cpi, amount, ctx.accounts.nome_mint.decimals)?;
+
+    emit!(VerifierUnstaked {
+        verifier,
+        amount,
+        stake: ctx.accounts.stake.amount
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct Unstake<'info> {
+    pub verifier: Signer<'info>,
+
+    #[account(mut, seeds = [GENOME_ROOT, SINGLE_CONFIG], bump)]
+    pub config: Account<'info, GenomeSingleConfig>,
+
+    #[account(mut, seeds = [GENOME_ROOT, STAKE, verifier.key().as_ref()], bump)]
+    pub stake: Account<'info, VerifierStake>,
+
+    #[account(constraint = nome_mint.key() == config.nome_mint @ GenomeError::InvalidNome)]
+    pub nome_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = nome_mint,
+        associated_token::authority = verifier,
+        associated_token::token_program = token_program,
+    )]
+    pub verifier_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = nome_mint,
+        associated_token::authority = stake,
+        associated_token::token_program = token_program,
+    )]
+    pub stake_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[event]
+pub struct VerifierUnstaked {
+    pub verifier: Pubkey,
+    pub amount: u64,
+    pub stake: u64,
+}
@@ -0,0 +1,102 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    data::{
+        BloomFilter, FinishMetaData, GenomeSingleConfig, RoleList, TokenInfo, Tournament,
+        TournamentConfig, TournamentStatus,
+    },
+    error::GenomeError,
+    realloc,
+    utils::{calculate_bloom_memory, initialize_bloom_filter, validate_params},
+    Role, BLOOM, FINISH, GENOME_ROOT, ROLE, SINGLE_CONFIG, TOKEN, TOURNAMENT,
+};
+
+/// Replaces the tournament config while nobody has registered yet. The asset mint, the
+/// sponsor and the sponsor pool are already locked in the reward pool and can't change.
+pub(crate) fn handle_update_tournament(
+    ctx: Context<UpdateTournament>,
+    tournament_id: u32,
+    tournament_config: TournamentConfig,
+) -> Result<()> {
+    let tournament = &mut ctx.accounts.tournament;
+    let bloom_filter = &mut ctx.accounts.bloom_filter;
+
+    require!(
+        tournament.team_count == 0 && tournament.registered_teams == 0,
+        GenomeError::RegistrationsExist
+    );
+    require!(
+        tournament_config.asset_mint == tournament.config.asset_mint
+            && tournament_config.native_sol == tournament.config.native_sol
+            && tournament_config.sponsor == tournament.config.sponsor
+            && tournament_config.sponsor_pool == tournament.config.sponsor_pool,
+        GenomeError::ImmutableConfig
+    );
+    validate_params(&tournament_config, &ctx.accounts.config, &ctx.accounts.token_info)?;
+
+    let organizer = ctx.accounts.organizer.to_account_info();
+    let false_precision = ctx.accounts.config.false_precision;
+    let bloom_memory = calculate_bloom_memory(
+        tournament_config.max_teams * tournament_config.team_size,
+        false_precision,
+    )?;
+    realloc(bloom_filter.to_account_info(), organizer.clone(), BloomFilter::space(bloom_memory))?;
+    initialize_bloom_filter(&tournament_config, &false_precision, bloom_filter)?;
+    bloom_filter.released.clear();
+
+    let finish_meta_space = FinishMetaData::space(
+        ctx.accounts.verifier_list.accounts.len(),
+        tournament_config.prize_distribution.len(),
+    );
+    realloc(ctx.accounts.finish_meta_data.to_account_info(), organizer, finish_meta_space)?;
+
+    tournament.config = tournament_config.clone();
+
+    emit!(TournamentUpdated {
+        tournament_id,
+        config: tournament_config
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(tournament_id: u32)]
+pub(crate) struct UpdateTournament<'info> {
+    #[account(mut, address = tournament.organizer @ GenomeError::NotAllowed)]
+    pub organizer: Signer<'info>,
+
+    #[account(seeds = [GENOME_ROOT, SINGLE_CONFIG], bump)]
+    pub config: Account<'info, GenomeSingleConfig>,
+
+    #[account(seeds = [GENOME_ROOT, ROLE, Role::Verifier.to_seed()], bump)]
+    pub verifier_list: Account<'info, RoleList>,
+
+    #[account(
+        seeds = [GENOME_ROOT, TOKEN, tournament.config.asset_mint.as_ref()],
+        bump,
+    )]
+    pub token_info: Account<'info, TokenInfo>,
+
+    #[account(
+        mut,
+        seeds = [GENOME_ROOT, TOURNAMENT, tournament_id.to_le_bytes().as_ref()],
+        constraint = tournament.status == TournamentStatus::New @ GenomeError::InvalidStatus,
+        bump
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    #[account(mut, seeds = [GENOME_ROOT, BLOOM, tournament_id.to_le_bytes().as_ref()], bump)]
+    pub bloom_filter: Box<Account<'info, BloomFilter>>,
+
+    #[account(mut, seeds = [GENOME_ROOT, FINISH, tournament_id.to_le_bytes().as_ref()], bump)]
+    pub finish_meta_data: Box<Account<'info, FinishMetaData>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub(crate) struct TournamentUpdated {
+    pub tournament_id: u32,
+    pub config: TournamentConfig,
+}
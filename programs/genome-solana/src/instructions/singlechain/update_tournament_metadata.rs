@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    data::{Tournament, TournamentMetadata, TournamentStatus},
+    error::GenomeError,
+    utils::validate_metadata,
+    GENOME_ROOT, METADATA, TOURNAMENT,
+};
+
+pub(crate) fn handle_update_tournament_metadata(
+    ctx: Context<UpdateTournamentMetadata>,
+    tournament_id: u32,
+    metadata: TournamentMetadata,
+) -> Result<()> {
+    validate_metadata(&metadata)?;
+
+    emit!(TournamentMetadataUpdated {
+        tournament_id,
+        name: metadata.name.clone(),
+        game: metadata.game.clone(),
+        rules_uri: metadata.rules_uri.clone(),
+        rules_hash: metadata.rules_hash,
+    });
+    ctx.accounts.metadata.set_inner(metadata);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(tournament_id: u32)]
+pub(crate) struct UpdateTournamentMetadata<'info> {
+    #[account(address = tournament.organizer @ GenomeError::NotAllowed)]
+    pub organizer: Signer<'info>,
+
+    #[account(
+        seeds = [GENOME_ROOT, TOURNAMENT, tournament_id.to_le_bytes().as_ref()],
+        constraint = tournament.status == TournamentStatus::New @ GenomeError::InvalidStatus,
+        bump
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    #[account(mut, seeds = [GENOME_ROOT, METADATA, tournament_id.to_le_bytes().as_ref()], bump)]
+    pub metadata: Account<'info, TournamentMetadata>,
+}
+
+#[event]
+pub(crate) struct TournamentMetadataUpdated {
+    pub tournament_id: u32,
+    pub name: String,
+    pub game: String,
+    pub rules_uri: String,
+    pub rules_hash: [u8; 32],
+}
@@ -0,0 +1,302 @@
+use std::cmp::Reverse;
+
+use crate::error::GenomeError;
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub(crate) enum MatchResult {
+    HomeWin,
+    AwayWin,
+    Draw,
+}
+
+/// Tiebreakers applied in the configured order to teams with equal points. Teams that are
+/// still tied afterwards keep the league order, which is by captain key.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub(crate) enum Tiebreaker {
+    /// More wins ranks higher.
+    Wins,
+    /// Fewer losses ranks higher.
+    FewestLosses,
+    /// More points earned in matches between the teams with equal points ranks higher.
+    HeadToHead,
+}
+
+#[derive(Default, Clone, AnchorDeserialize, AnchorSerialize, Debug, PartialEq, Eq, InitSpace)]
+pub(crate) struct Standing {
+    pub(crate) captain: Pubkey,
+    pub(crate) wins: u16,
+    pub(crate) draws: u16,
+    pub(crate) losses: u16,
+    pub(crate) points: u32,
+}
+
+#[derive(Clone, AnchorDeserialize, AnchorSerialize, Debug, PartialEq, Eq, InitSpace)]
+pub(crate) struct LeagueMatch {
+    pub(crate) home: u16,
+    pub(crate) away: u16,
+    pub(crate) result: Option<MatchResult>,
+    pub(crate) home_votes: u64,
+    pub(crate) away_votes: u64,
+    pub(crate) draw_votes: u64,
+}
+
+impl LeagueMatch {
+    fn points(&self, team: u16) -> u32 {
+        match self.result {
+            Some(MatchResult::HomeWin) if team == self.home => League::WIN_POINTS,
+            Some(MatchResult::AwayWin) if team == self.away => League::WIN_POINTS,
+            Some(MatchResult::Draw) => League::DRAW_POINTS,
+            _ => 0,
+        }
+    }
+}
+
+/// Round-robin league where every team plays every other team once. Matches refer to
+/// teams by their index in `standings`.
+#[account]
+#[derive(InitSpace, Debug)]
+pub(crate) struct League {
+    pub(crate) payer: Pubkey,
+    #[max_len(0)]
+    pub(crate) standings: Vec<Standing>,
+    #[max_len(0)]
+    pub(crate) matches: Vec<LeagueMatch>,
+}
+
+impl League {
+    pub(crate) const MAX_TEAMS: usize = 16;
+    pub(crate) const MAX_TIEBREAKERS: usize = 3;
+    const WIN_POINTS: u32 = 3;
+    const DRAW_POINTS: u32 = 1;
+
+    fn matches_count(teams_count: usize) -> usize {
+        teams_count * teams_count.saturating_sub(1) / 2
+    }
+
+    pub(crate) fn space(teams_count: usize) -> usize {
+        Self::DISCRIMINATOR.len()
+            + Self::INIT_SPACE
+            + Standing::INIT_SPACE * teams_count
+            + LeagueMatch::INIT_SPACE * Self::matches_count(teams_count)
+    }
+
+    pub(crate) fn new(captains: &[Pubkey]) -> Self {
+        let standings = captains
+            .iter()
+            .map(|&captain| Standing {
+                captain,
+                ..Default::default()
+            })
+            .collect();
+
+        let mut matches = Vec::with_capacity(Self::matches_count(captains.len()));
+        for home in 0..captains.len() {
+            for away in home + 1..captains.len() {
+                matches.push(LeagueMatch {
+                    home: home as u16,
+                    away: away as u16,
+                    result: None,
+                    home_votes: 0,
+                    away_votes: 0,
+                    draw_votes: 0,
+                });
+            }
+        }
+
+        Self {
+            payer: Pubkey::default(),
+            standings,
+            matches,
+        }
+    }
+
+    pub(crate) fn vote(
+        &mut self,
+        match_index: usize,
+        result: MatchResult,
+        verifier_index: usize,
+    ) -> Result<u64> {
+        let game = self.matches.get_mut(match_index).ok_or(GenomeError::InvalidMatch)?;
+        require!(game.result.is_none(), GenomeError::InvalidMatch);
+        require!(
+            ((game.home_votes | game.away_votes | game.draw_votes) >> verifier_index) & 1 == 0,
+            GenomeError::AlreadyVoted
+        );
+
+        let votes = match result {
+            MatchResult::HomeWin => &mut game.home_votes,
+            MatchResult::AwayWin => &mut game.away_votes,
+            MatchResult::Draw => &mut game.draw_votes,
+        };
+        *votes |= 1 << verifier_index;
+
+        Ok(votes.count_ones() as u64)
+    }
+
+    pub(crate) fn settle(&mut self, match_index: usize, result: MatchResult) {
+        let game = &mut self.matches[match_index];
+        game.result = Some(result);
+        let (home, away) = (game.home as usize, game.away as usize);
+
+        match result {
+            MatchResult::HomeWin => self.record_win(home, away),
+            MatchResult::AwayWin => self.record_win(away, home),
+            MatchResult::Draw => {
+                for team in [home, away] {
+                    self.standings[team].draws += 1;
+                    self.standings[team].points += Self::DRAW_POINTS;
+                }
+            }
+        }
+    }
+
+    fn record_win(&mut self, winner: usize, loser: usize) {
+        self.standings[winner].wins += 1;
+        self.standings[winner].points += Self::WIN_POINTS;
+        self.standings[loser].losses += 1;
+    }
+
+    pub(crate) fn is_finished(&self) -> bool {
+        self.matches.iter().all(|game| game.result.is_some())
+    }
+
+    /// Points a team earned against the other teams with the same amount of points.
+    fn head_to_head_points(&self, team: usize) -> u32 {
+        let points = self.standings[team].points;
+        self.matches
+            .iter()
+            .filter(|game| {
+                let (home, away) = (game.home as usize, game.away as usize);
+                (home == team && self.standings[away].points == points)
+                    || (away == team && self.standings[home].points == points)
+            })
+            .map(|game| game.points(team as u16))
+            .sum()
+    }
+
+    /// Returns the first `places_count` captains ordered by the final standings, or `None`
+    /// while some matches are still unsettled.
+    pub(crate) fn placements(
+        &self,
+        places_count: usize,
+        tiebreakers: &[Tiebreaker],
+    ) -> Option<Vec<Pubkey>> {
+        if !self.is_finished() {
+            return None;
+        }
+
+        let mut ranking: Vec<(usize, Vec<Reverse<u32>>)> = (0..self.standings.len())
+            .map(|team| {
+                let standing = &self.standings[team];
+                let mut key = vec![Reverse(standing.points)];
+                key.extend(tiebreakers.iter().map(|tiebreaker| match tiebreaker {
+                    Tiebreaker::Wins => Reverse(standing.wins as u32),
+                    Tiebreaker::FewestLosses => Reverse(u32::MAX - standing.losses as u32),
+                    Tiebreaker::HeadToHead => Reverse(self.head_to_head_points(team)),
+                }));
+                (team, key)
+            })
+            .collect();
+        ranking.sort_by(|a, b| a.1.cmp(&b.1));
+
+        Some(
+            ranking
+                .into_iter()
+                .take(places_count)
+                .map(|(team, _)| self.standings[team].captain)
+                .collect(),
+        )
+    }
+
+    pub(crate) fn validate_tiebreakers(tiebreakers: &[Tiebreaker]) -> bool {
+        tiebreakers
+            .iter()
+            .enumerate()
+            .all(|(i, tiebreaker)| !tiebreakers[i + 1..].contains(tiebreaker))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn captains(count: usize) -> Vec<Pubkey> {
+        let mut captains: Vec<Pubkey> = (0..count).map(|_| Pubkey::new_unique()).collect();
+        captains.sort();
+        captains
+    }
+
+    #[test]
+    fn test_league_schedule() {
+        let league = League::new(&captains(4));
+
+        assert_eq!(league.standings.len(), 4);
+        assert_eq!(league.matches.len(), 6);
+        assert_eq!((league.matches[0].home, league.matches[0].away), (0, 1));
+        assert_eq!((league.matches[5].home, league.matches[5].away), (2, 3));
+    }
+
+    #[test]
+    fn test_league_vote() {
+        let mut league = League::new(&captains(2));
+
+        assert_eq!(league.vote(0, MatchResult::Draw, 0).unwrap(), 1);
+        assert_eq!(league.vote(0, MatchResult::Draw, 1).unwrap(), 2);
+        assert!(league.vote(0, MatchResult::HomeWin, 1).is_err());
+        assert!(league.vote(1, MatchResult::HomeWin, 2).is_err());
+
+        league.settle(0, MatchResult::Draw);
+        assert!(league.vote(0, MatchResult::Draw, 2).is_err());
+        assert_eq!(league.standings[0].points, 1);
+        assert_eq!(league.standings[1].draws, 1);
+    }
+
+    #[test]
+    fn test_league_placements_with_tiebreakers() {
+        let teams = captains(3);
+        let mut league = League::new(&teams);
+
+        // 0 beats 1, 1 beats 2, 2 beats 0: everybody has 3 points.
+        league.settle(0, MatchResult::HomeWin);
+        assert_eq!(league.placements(3, &[]), None);
+        league.settle(1, MatchResult::AwayWin);
+        league.settle(2, MatchResult::HomeWin);
+        assert_eq!(league.placements(3, &[Tiebreaker::Wins]), Some(teams.clone()));
+
+        let mut league = League::new(&teams);
+        // 0 draws with 1, 0 beats 2, 2 beats 1: 0 has 4 points, 2 has 3, 1 has 1.
+        league.settle(0, MatchResult::Draw);
+        league.settle(1, MatchResult::HomeWin);
+        league.settle(2, MatchResult::AwayWin);
+        assert_eq!(league.placements(2, &[]), Some(vec![teams[0], teams[2]]));
+    }
+
+    #[test]
+    fn test_league_head_to_head() {
+        let teams = captains(4);
+        let mut league = League::new(&teams);
+
+        // 2 and 3 both finish with 6 points and one loss, 3 won their direct match.
+        league.settle(0, MatchResult::HomeWin); // 0 - 1
+        league.settle(1, MatchResult::AwayWin); // 0 - 2
+        league.settle(2, MatchResult::AwayWin); // 0 - 3
+        league.settle(3, MatchResult::AwayWin); // 1 - 2
+        league.settle(4, MatchResult::HomeWin); // 1 - 3
+        league.settle(5, MatchResult::AwayWin); // 2 - 3
+
+        assert_eq!(league.standings[2].points, 6);
+        assert_eq!(league.standings[3].points, 6);
+        assert_eq!(league.placements(2, &[Tiebreaker::HeadToHead]), Some(vec![teams[3], teams[2]]));
+        assert_eq!(
+            league.placements(2, &[Tiebreaker::FewestLosses, Tiebreaker::HeadToHead]),
+            Some(vec![teams[3], teams[2]])
+        );
+    }
+
+    #[test]
+    fn test_league_tiebreakers_validation() {
+        assert!(League::validate_tiebreakers(&[Tiebreaker::HeadToHead, Tiebreaker::Wins]));
+        assert!(!League::validate_tiebreakers(&[Tiebreaker::Wins, Tiebreaker::Wins]));
+    }
+}
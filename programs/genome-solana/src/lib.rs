@@ -1,8 +1,14 @@
 #![allow(unexpected_cfgs)]
 
+mod bonus;
+mod bracket;
 mod data;
 mod error;
+mod fees;
 mod instructions;
+mod league;
+mod reward_pool;
+mod soulbound;
 mod team;
 mod utils;
 
@@ -10,7 +16,8 @@ use instructions::*;
 
 use anchor_lang::prelude::*;
 
-use data::{GenomeOmniConfig, GenomeSingleConfig, Role, TournamentConfig};
+use data::{GenomeOmniConfig, GenomeSingleConfig, Role, TournamentConfig, TournamentMetadata};
+use league::MatchResult;
 
 declare_id!("572G4eB1NNusfqGj3DVTZw1ZooweLBiaA3ko7fLhSsV2");
 
@@ -36,6 +43,22 @@ const CONSENSUS: &[u8] = b"consensus";
 const FINISH: &[u8] = b"finish";
 #[constant]
 const PLATFORM: &[u8] = b"platform";
+#[constant]
+const BRACKET: &[u8] = b"bracket";
+#[constant]
+const LEAGUE: &[u8] = b"league";
+#[constant]
+const METADATA: &[u8] = b"metadata";
+#[constant]
+const SPONSORSHIP: &[u8] = b"sponsorship";
+#[constant]
+const BONUS: &[u8] = b"bonus";
+#[constant]
+const TROPHY: &[u8] = b"trophy";
+#[constant]
+const BADGE: &[u8] = b"badge";
+#[constant]
+const STAKE: &[u8] = b"stake";
 
 #[cfg(feature = "localnet")]
 const DEPLOYER: Pubkey = pubkey!("CB39FqtnDdACX9XkwjsA2gYGd7ZfxjveMewhxRoB9c8k");
@@ -77,8 +100,27 @@ mod genome_solana {
     pub fn create_tournament(
         ctx: Context<CreateTournament>,
         tournament_config: TournamentConfig,
+        metadata: TournamentMetadata,
+    ) -> Result<()> {
+        handle_create_tournament(ctx, tournament_config, metadata)
+    }
+
+    #[instruction(discriminator = b"updtmnt")]
+    pub fn update_tournament(
+        ctx: Context<UpdateTournament>,
+        tournament_id: u32,
+        tournament_config: TournamentConfig,
     ) -> Result<()> {
-        handle_create_tournament(ctx, tournament_config)
+        handle_update_tournament(ctx, tournament_id, tournament_config)
+    }
+
+    #[instruction(discriminator = b"updtmeta")]
+    pub fn update_tournament_metadata(
+        ctx: Context<UpdateTournamentMetadata>,
+        tournament_id: u32,
+        metadata: TournamentMetadata,
+    ) -> Result<()> {
+        handle_update_tournament_metadata(ctx, tournament_id, metadata)
     }
 
     #[instruction(discriminator = b"regtmnt")]
@@ -89,11 +131,84 @@ mod genome_solana {
         handle_register_tournament(ctx, register_params)
     }
 
+    #[instruction(discriminator = b"addspnsr")]
+    pub fn add_sponsorship(
+        ctx: Context<AddSponsorship>,
+        tournament_id: u32,
+        amount: u64,
+    ) -> Result<()> {
+        handle_add_sponsorship(ctx, tournament_id, amount)
+    }
+
+    #[instruction(discriminator = b"addbonus")]
+    pub fn add_bonus_pool(
+        ctx: Context<AddBonusPool>,
+        tournament_id: u32,
+        amount: u64,
+    ) -> Result<()> {
+        handle_add_bonus_pool(ctx, tournament_id, amount)
+    }
+
+    #[instruction(discriminator = b"setalwls")]
+    pub fn set_allowlist(
+        ctx: Context<SetAllowlist>,
+        tournament_id: u32,
+        allowlist_root: Option<[u8; 32]>,
+    ) -> Result<()> {
+        handle_set_allowlist(ctx, tournament_id, allowlist_root)
+    }
+
+    #[instruction(discriminator = b"acptinvt")]
+    pub fn accept_invite(
+        ctx: Context<AcceptInvite>,
+        tournament_id: u32,
+        captain: Pubkey,
+    ) -> Result<()> {
+        handle_accept_invite(ctx, tournament_id, captain)
+    }
+
+    #[instruction(discriminator = b"exprinvt")]
+    pub fn expire_invites(
+        ctx: Context<ExpireInvites>,
+        tournament_id: u32,
+        captain: Pubkey,
+    ) -> Result<()> {
+        handle_expire_invites(ctx, tournament_id, captain)
+    }
+
+    #[instruction(discriminator = b"leavteam")]
+    pub fn leave_team(ctx: Context<LeaveTeam>, tournament_id: u32, captain: Pubkey) -> Result<()> {
+        handle_leave_team(ctx, tournament_id, captain)
+    }
+
+    #[instruction(discriminator = b"dsbdteam")]
+    pub fn disband_team<'info>(
+        ctx: Context<'_, '_, 'info, 'info, DisbandTeam<'info>>,
+        tournament_id: u32,
+    ) -> Result<()> {
+        handle_disband_team(ctx, tournament_id)
+    }
+
     #[instruction(discriminator = b"bloomprc")]
     pub fn set_bloom_precision(ctx: Context<SetBloomPrecision>, new_precision: u64) -> Result<()> {
         handle_set_bloom_precision(ctx, new_precision)
     }
 
+    #[instruction(discriminator = b"cnclpnlt")]
+    pub fn set_cancel_penalty(ctx: Context<SetCancelPenalty>, penalty: u64) -> Result<()> {
+        handle_set_cancel_penalty(ctx, penalty)
+    }
+
+    #[instruction(discriminator = b"clmperio")]
+    pub fn set_claim_period(ctx: Context<SetClaimPeriod>, claim_period: u64) -> Result<()> {
+        handle_set_claim_period(ctx, claim_period)
+    }
+
+    #[instruction(discriminator = b"slshrate")]
+    pub fn set_slash_rate(ctx: Context<SetSlashRate>, slash_rate: u64) -> Result<()> {
+        handle_set_slash_rate(ctx, slash_rate)
+    }
+
     #[instruction(discriminator = b"strttmnt")]
     pub fn start_tournament(ctx: Context<StartTournament>, tournament_id: u32) -> Result<()> {
         handle_start_tournament(ctx, tournament_id)
@@ -104,29 +219,112 @@ mod genome_solana {
         handle_cancel_tournament(ctx, tournament_id)
     }
 
+    #[instruction(discriminator = b"orgcncl")]
+    pub fn organizer_cancel(ctx: Context<OrganizerCancel>, tournament_id: u32) -> Result<()> {
+        handle_organizer_cancel(ctx, tournament_id)
+    }
+
+    #[instruction(discriminator = b"exprtmnt")]
+    pub fn expire_tournament(ctx: Context<ExpireTournament>, tournament_id: u32) -> Result<()> {
+        handle_expire_tournament(ctx, tournament_id)
+    }
+
+    #[instruction(discriminator = b"crtbrckt")]
+    pub fn create_bracket<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CreateBracket<'info>>,
+        tournament_id: u32,
+    ) -> Result<()> {
+        handle_create_bracket(ctx, tournament_id)
+    }
+
+    #[instruction(discriminator = b"rprtmtch")]
+    pub fn report_match(
+        ctx: Context<ReportMatch>,
+        tournament_id: u32,
+        match_index: u16,
+        winner: Pubkey,
+    ) -> Result<()> {
+        handle_report_match(ctx, tournament_id, match_index, winner)
+    }
+
+    #[instruction(discriminator = b"crtleage")]
+    pub fn create_league<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CreateLeague<'info>>,
+        tournament_id: u32,
+    ) -> Result<()> {
+        handle_create_league(ctx, tournament_id)
+    }
+
+    #[instruction(discriminator = b"rprtlgmt")]
+    pub fn report_league_match(
+        ctx: Context<ReportLeagueMatch>,
+        tournament_id: u32,
+        match_index: u16,
+        result: MatchResult,
+    ) -> Result<()> {
+        handle_report_league_match(ctx, tournament_id, match_index, result)
+    }
+
     #[instruction(discriminator = b"fnshtmnt")]
     pub fn finish_tournament(
         ctx: Context<FinishTournament>,
         tournament_id: u32,
-        winner: Pubkey,
+        placements: Vec<Pubkey>,
     ) -> Result<()> {
-        handle_finish_tournament(ctx, tournament_id, winner)
+        handle_finish_tournament(ctx, tournament_id, placements)
     }
 
     #[instruction(discriminator = b"clmrewrd")]
-    pub fn claim_reward(
-        ctx: Context<ClaimReward>,
+    pub fn claim_reward<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ClaimReward<'info>>,
         tournament_id: u32,
         captain: Pubkey,
     ) -> Result<()> {
         handle_claim_reward(ctx, tournament_id, captain)
     }
 
+    #[instruction(discriminator = b"clmbadge")]
+    pub fn claim_badge(
+        ctx: Context<ClaimBadge>,
+        tournament_id: u32,
+        captain: Pubkey,
+    ) -> Result<()> {
+        handle_claim_badge(ctx, tournament_id, captain)
+    }
+
+    #[instruction(discriminator = b"dstrwrds")]
+    pub fn distribute_rewards<'info>(
+        ctx: Context<'_, '_, 'info, 'info, DistributeRewards<'info>>,
+        tournament_id: u32,
+        captain: Pubkey,
+    ) -> Result<()> {
+        handle_distribute_rewards(ctx, tournament_id, captain)
+    }
+
     #[instruction(discriminator = b"clmrlfnd")]
     pub fn claim_role_fund(ctx: Context<ClaimRoleFund>, amount: u64) -> Result<()> {
         handle_claim_role_fund(ctx, amount)
     }
 
+    #[instruction(discriminator = b"stakenom")]
+    pub fn stake(ctx: Context<Stake>, amount: u64) -> Result<()> {
+        handle_stake(ctx, amount)
+    }
+
+    #[instruction(discriminator = b"unstknom")]
+    pub fn unstake(ctx: Context<Unstake>, amount: u64) -> Result<()> {
+        handle_unstake(ctx, amount)
+    }
+
+    #[instruction(discriminator = b"sttlvrfr")]
+    pub fn settle_verifier(
+        ctx: Context<SettleVerifier>,
+        tournament_id: u32,
+        verifier: Pubkey,
+    ) -> Result<()> {
+        handle_settle_verifier(ctx, tournament_id, verifier)
+    }
+
     #[instruction(discriminator = b"clmspfnd")]
     pub fn claim_sponsor_refund(
         ctx: Context<ClaimSponsorRefund>,
@@ -144,6 +342,35 @@ mod genome_solana {
         handle_claim_refund(ctx, tournament_id, captain)
     }
 
+    #[instruction(discriminator = b"swpuncld")]
+    pub fn sweep_unclaimed(ctx: Context<SweepUnclaimed>, tournament_id: u32) -> Result<()> {
+        handle_sweep_unclaimed(ctx, tournament_id)
+    }
+
+    #[instruction(discriminator = b"closteam")]
+    pub fn close_team(ctx: Context<CloseTeam>, tournament_id: u32, captain: Pubkey) -> Result<()> {
+        handle_close_team(ctx, tournament_id, captain)
+    }
+
+    #[instruction(discriminator = b"clsspons")]
+    pub fn close_sponsorship(
+        ctx: Context<CloseSponsorship>,
+        tournament_id: u32,
+        sponsor: Pubkey,
+    ) -> Result<()> {
+        handle_close_sponsorship(ctx, tournament_id, sponsor)
+    }
+
+    #[instruction(discriminator = b"clstmnt")]
+    pub fn close_tournament(ctx: Context<CloseTournament>, tournament_id: u32) -> Result<()> {
+        handle_close_tournament(ctx, tournament_id)
+    }
+
+    #[instruction(discriminator = b"clsbonus")]
+    pub fn close_bonus_pool(ctx: Context<CloseBonusPool>, tournament_id: u32) -> Result<()> {
+        handle_close_bonus_pool(ctx, tournament_id)
+    }
+
     #[instruction(discriminator = b"withdraw")]
     pub fn withdraw(ctx: Context<WithdrawPlatformFee>, amount: u64) -> Result<()> {
         handle_withdraw(ctx, amount)
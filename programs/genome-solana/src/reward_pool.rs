@@ -0,0 +1,144 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
+};
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::{data::Tournament, error::GenomeError, GENOME_ROOT, TOURNAMENT};
+
+/// Reward pool of a tournament. SPL assets are held in the tournament's associated token
+/// account, native SOL is held as lamports of the tournament PDA on top of its rent.
+pub(crate) struct RewardPool<'info> {
+    tournament: AccountInfo<'info>,
+    tournament_id: u32,
+    bump: u8,
+    mint: AccountInfo<'info>,
+    decimals: u8,
+    pool_ata: Option<AccountInfo<'info>>,
+    pool_amount: u64,
+    token_program: AccountInfo<'info>,
+}
+
+impl<'info> RewardPool<'info> {
+    /// The pool token account has to be passed for SPL assets and omitted for native SOL.
+    pub(crate) fn new(
+        tournament: &Account<'info, Tournament>,
+        bump: u8,
+        mint: &InterfaceAccount<'info, Mint>,
+        pool_ata: Option<&InterfaceAccount<'info, TokenAccount>>,
+        token_program: &Interface<'info, TokenInterface>,
+    ) -> Result<Self> {
+        require!(tournament.config.native_sol == pool_ata.is_none(), GenomeError::InvalidToken);
+
+        Ok(Self {
+            tournament: tournament.to_account_info(),
+            tournament_id: tournament.id,
+            bump,
+            mint: mint.to_account_info(),
+            decimals: mint.decimals,
+            pool_ata: pool_ata.map(|ata| ata.to_account_info()),
+            pool_amount: pool_ata.map(|ata| ata.amount).unwrap_or_default(),
+            token_program: token_program.to_account_info(),
+        })
+    }
+
+    pub(crate) fn is_native(&self) -> bool {
+        self.pool_ata.is_none()
+    }
+
+    /// Amount available for payouts.
+    pub(crate) fn balance(&self) -> Result<u64> {
+        if self.is_native() {
+            let rent = Rent::get()?.minimum_balance(self.tournament.data_len());
+            Ok(self.tournament.lamports().saturating_sub(rent))
+        } else {
+            Ok(self.pool_amount)
+        }
+    }
+
+    /// Moves `amount` into the pool and returns how much the pool actually received, which is
+    /// less than `amount` for mints with a transfer fee. For SPL assets `from` is the source
+    /// token account and `authority` its owner or delegate, for native SOL `authority` is the
+    /// paying wallet.
+    pub(crate) fn deposit(
+        &self,
+        from: Option<AccountInfo<'info>>,
+        authority: AccountInfo<'info>,
+        system_program: AccountInfo<'info>,
+        amount: u64,
+    ) -> Result<u64> {
+        if amount == 0 {
+            return Ok(0);
+        }
+
+        match &self.pool_ata {
+            None => {
+                let accounts = Transfer {
+                    from: authority,
+                    to: self.tournament.clone(),
+                };
+                transfer(CpiContext::new(system_program, accounts), amount)?;
+                Ok(amount)
+            }
+            Some(pool_ata) => {
+                let accounts = TransferChecked {
+                    from: from.ok_or(GenomeError::InvalidToken)?,
+                    to: pool_ata.clone(),
+                    mint: self.mint.clone(),
+                    authority,
+                };
+                let cpi = CpiContext::new(self.token_program.clone(), accounts);
+                let before = token_amount(pool_ata)?;
+                transfer_checked(cpi, amount, self.decimals)?;
+                Ok(token_amount(pool_ata)? - before)
+            }
+        }
+    }
+
+    /// Picks where a payout goes: the recipient's token account for SPL assets, which has to be
+    /// omitted for native SOL, where the wallet is paid directly.
+    pub(crate) fn recipient(
+        &self,
+        ata: &Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+        wallet: AccountInfo<'info>,
+    ) -> Result<AccountInfo<'info>> {
+        require!(self.is_native() == ata.is_none(), GenomeError::InvalidToken);
+        Ok(ata.as_ref().map(|ata| ata.to_account_info()).unwrap_or(wallet))
+    }
+
+    /// Pays `amount` out of the pool to a token account, or to a wallet for native SOL.
+    pub(crate) fn pay(&self, to: AccountInfo<'info>, amount: u64) -> Result<()> {
+        if amount == 0 {
+            return Ok(());
+        }
+
+        match &self.pool_ata {
+            None => {
+                self.tournament.sub_lamports(amount)?;
+                to.add_lamports(amount)?;
+                Ok(())
+            }
+            Some(pool_ata) => {
+                let id_bytes = self.tournament_id.to_le_bytes();
+                let bump = [self.bump];
+                let tournament_seeds: &[&[u8]] = &[GENOME_ROOT, TOURNAMENT, &id_bytes, &bump];
+                let signer = &[tournament_seeds];
+                let accounts = TransferChecked {
+                    from: pool_ata.clone(),
+                    to,
+                    mint: self.mint.clone(),
+                    authority: self.tournament.clone(),
+                };
+                let cpi = CpiContext::new_with_signer(self.token_program.clone(), accounts, signer);
+                transfer_checked(cpi, amount, self.decimals)
+            }
+        }
+    }
+}
+
+fn token_amount(token_account: &AccountInfo) -> Result<u64> {
+    let data = token_account.try_borrow_data()?;
+    Ok(TokenAccount::try_deserialize(&mut &data[..])?.amount)
+}
@@ -0,0 +1,280 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{
+        allocate, assign, create_account, transfer, Allocate, Assign, CreateAccount, Transfer,
+    },
+};
+use anchor_spl::{
+    associated_token::{create_idempotent, Create},
+    token_2022::{
+        initialize_mint2, mint_to,
+        spl_token_2022::{extension::ExtensionType, state::Mint},
+        InitializeMint2, MintTo,
+    },
+    token_2022_extensions::{
+        metadata_pointer_initialize, non_transferable_mint_initialize,
+        spl_pod::optional_keys::OptionalNonZeroPubkey,
+        spl_token_metadata_interface::state::{Field, TokenMetadata},
+        token_metadata_initialize, token_metadata_update_field, MetadataPointerInitialize,
+        NonTransferableMintInitialize, TokenMetadataInitialize, TokenMetadataUpdateField,
+    },
+};
+
+use crate::{BADGE, GENOME_ROOT, TROPHY};
+
+/// Issues non-transferable Token-2022 tokens with on-mint metadata: trophies for the members of
+/// placed teams and participation badges for the members of completed teams. Every placed team
+/// gets its own trophy mint, while all badges of a tournament share one mint. Mints are PDAs
+/// created on the first claim, and the authority PDA is both the mint and the metadata update
+/// authority.
+pub(crate) struct SoulboundMint<'info> {
+    pub(crate) payer: AccountInfo<'info>,
+    pub(crate) authority: AccountInfo<'info>,
+    pub(crate) mint: AccountInfo<'info>,
+    pub(crate) token_program: AccountInfo<'info>,
+    pub(crate) associated_token_program: AccountInfo<'info>,
+    pub(crate) system_program: AccountInfo<'info>,
+    pub(crate) authority_bump: u8,
+    pub(crate) mint_bump: u8,
+}
+
+impl<'info> SoulboundMint<'info> {
+    const TROPHY_NAME: &'static str = "Genome Trophy";
+    const TROPHY_SYMBOL: &'static str = "TROPHY";
+    const BADGE_NAME: &'static str = "Genome Participant";
+    const BADGE_SYMBOL: &'static str = "BADGE";
+    const DECIMALS: u8 = 0;
+
+    fn trophy_metadata(
+        tournament_id: u32,
+        place: usize,
+        captain: &Pubkey,
+    ) -> Vec<(String, String)> {
+        vec![
+            ("tournament_id".to_string(), tournament_id.to_string()),
+            ("placement".to_string(), (place + 1).to_string()),
+            ("captain".to_string(), captain.to_string()),
+        ]
+    }
+
+    fn badge_metadata(tournament_id: u32) -> Vec<(String, String)> {
+        vec![("tournament_id".to_string(), tournament_id.to_string())]
+    }
+
+    /// Mints one trophy of the team to the recipient's Token-2022 associated token account.
+    pub(crate) fn award_trophy(
+        &self,
+        tournament_id: u32,
+        place: usize,
+        captain: &Pubkey,
+        recipient: AccountInfo<'info>,
+        recipient_ata: AccountInfo<'info>,
+    ) -> Result<()> {
+        if self.mint.data_is_empty() {
+            let id_bytes = tournament_id.to_le_bytes();
+            self.create_mint(
+                &[
+                    GENOME_ROOT,
+                    TROPHY,
+                    &id_bytes,
+                    captain.as_ref(),
+                    &[self.mint_bump],
+                ],
+                &[GENOME_ROOT, TROPHY, &[self.authority_bump]],
+                Self::TROPHY_NAME,
+                Self::TROPHY_SYMBOL,
+                Self::trophy_metadata(tournament_id, place, captain),
+            )?;
+        }
+        self.mint_one(&[GENOME_ROOT, TROPHY, &[self.authority_bump]], recipient, recipient_ata)
+    }
+
+    /// Mints one participation badge of the tournament to the recipient's Token-2022
+    /// associated token account.
+    pub(crate) fn award_badge(
+        &self,
+        tournament_id: u32,
+        recipient: AccountInfo<'info>,
+        recipient_ata: AccountInfo<'info>,
+    ) -> Result<()> {
+        if self.mint.data_is_empty() {
+            let id_bytes = tournament_id.to_le_bytes();
+            self.create_mint(
+                &[GENOME_ROOT, BADGE, &id_bytes, &[self.mint_bump]],
+                &[GENOME_ROOT, BADGE, &[self.authority_bump]],
+                Self::BADGE_NAME,
+                Self::BADGE_SYMBOL,
+                Self::badge_metadata(tournament_id),
+            )?;
+        }
+        self.mint_one(&[GENOME_ROOT, BADGE, &[self.authority_bump]], recipient, recipient_ata)
+    }
+
+    fn mint_one(
+        &self,
+        authority_seeds: &[&[u8]],
+        recipient: AccountInfo<'info>,
+        recipient_ata: AccountInfo<'info>,
+    ) -> Result<()> {
+        let accounts = Create {
+            payer: self.payer.clone(),
+            associated_token: recipient_ata.clone(),
+            authority: recipient,
+            mint: self.mint.clone(),
+            system_program: self.system_program.clone(),
+            token_program: self.token_program.clone(),
+        };
+        create_idempotent(CpiContext::new(self.associated_token_program.clone(), accounts))?;
+
+        let accounts = MintTo {
+            mint: self.mint.clone(),
+            to: recipient_ata,
+            authority: self.authority.clone(),
+        };
+        mint_to(
+            CpiContext::new_with_signer(self.token_program.clone(), accounts, &[authority_seeds]),
+            1,
+        )
+    }
+
+    fn create_mint(
+        &self,
+        mint_seeds: &[&[u8]],
+        authority_seeds: &[&[u8]],
+        name: &str,
+        symbol: &str,
+        additional_metadata: Vec<(String, String)>,
+    ) -> Result<()> {
+        let token_metadata = TokenMetadata {
+            update_authority: OptionalNonZeroPubkey(self.authority.key()),
+            mint: self.mint.key(),
+            name: name.to_string(),
+            symbol: symbol.to_string(),
+            uri: String::new(),
+            additional_metadata: additional_metadata.clone(),
+        };
+
+        // The mint is created with room for the fixed-size extensions only, the metadata
+        // instructions realloc it, so its rent has to be paid upfront.
+        let space = ExtensionType::try_calculate_account_len::<Mint>(&[
+            ExtensionType::NonTransferable,
+            ExtensionType::MetadataPointer,
+        ])?;
+        let lamports = Rent::get()?.minimum_balance(space + token_metadata.tlv_size_of()?);
+
+        let mint_signer = &[mint_seeds];
+        let balance = self.mint.lamports();
+        if balance == 0 {
+            let accounts = CreateAccount {
+                from: self.payer.clone(),
+                to: self.mint.clone(),
+            };
+            create_account(
+                CpiContext::new_with_signer(self.system_program.clone(), accounts, mint_signer),
+                lamports,
+                space as u64,
+                self.token_program.key,
+            )?;
+        } else {
+            // Somebody has sent lamports to the mint address, which makes `create_account` fail.
+            if balance < lamports {
+                let accounts = Transfer {
+                    from: self.payer.clone(),
+                    to: self.mint.clone(),
+                };
+                transfer(
+                    CpiContext::new(self.system_program.clone(), accounts),
+                    lamports - balance,
+                )?;
+            }
+            let accounts = Allocate {
+                account_to_allocate: self.mint.clone(),
+            };
+            allocate(
+                CpiContext::new_with_signer(self.system_program.clone(), accounts, mint_signer),
+                space as u64,
+            )?;
+            let accounts = Assign {
+                account_to_assign: self.mint.clone(),
+            };
+            assign(
+                CpiContext::new_with_signer(self.system_program.clone(), accounts, mint_signer),
+                self.token_program.key,
+            )?;
+        }
+
+        let accounts = NonTransferableMintInitialize {
+            token_program_id: self.token_program.clone(),
+            mint: self.mint.clone(),
+        };
+        non_transferable_mint_initialize(CpiContext::new(self.token_program.clone(), accounts))?;
+
+        let accounts = MetadataPointerInitialize {
+            token_program_id: self.token_program.clone(),
+            mint: self.mint.clone(),
+        };
+        metadata_pointer_initialize(
+            CpiContext::new(self.token_program.clone(), accounts),
+            Some(self.authority.key()),
+            Some(self.mint.key()),
+        )?;
+
+        let accounts = InitializeMint2 {
+            mint: self.mint.clone(),
+        };
+        initialize_mint2(
+            CpiContext::new(self.token_program.clone(), accounts),
+            Self::DECIMALS,
+            self.authority.key,
+            None,
+        )?;
+
+        let signer = &[authority_seeds];
+        let accounts = TokenMetadataInitialize {
+            program_id: self.token_program.clone(),
+            metadata: self.mint.clone(),
+            update_authority: self.authority.clone(),
+            mint_authority: self.authority.clone(),
+            mint: self.mint.clone(),
+        };
+        token_metadata_initialize(
+            CpiContext::new_with_signer(self.token_program.clone(), accounts, signer),
+            token_metadata.name,
+            token_metadata.symbol,
+            token_metadata.uri,
+        )?;
+
+        for (key, value) in additional_metadata {
+            let accounts = TokenMetadataUpdateField {
+                program_id: self.token_program.clone(),
+                metadata: self.mint.clone(),
+                update_authority: self.authority.clone(),
+            };
+            token_metadata_update_field(
+                CpiContext::new_with_signer(self.token_program.clone(), accounts, signer),
+                Field::Key(key),
+                value,
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_soulbound_metadata() {
+        let captain = Pubkey::new_unique();
+        let metadata = SoulboundMint::trophy_metadata(7, 0, &captain);
+
+        assert_eq!(metadata[0], ("tournament_id".to_string(), "7".to_string()));
+        assert_eq!(metadata[1], ("placement".to_string(), "1".to_string()));
+        assert_eq!(metadata[2], ("captain".to_string(), captain.to_string()));
+
+        let metadata = SoulboundMint::badge_metadata(7);
+        assert_eq!(metadata, vec![("tournament_id".to_string(), "7".to_string())]);
+    }
+}
@@ -6,6 +6,11 @@ pub(crate) struct ParticipantInfo {
     pub(crate) pubkey: Pubkey,
     pub(crate) paid_by_captain: bool,
     pub(crate) claimed: bool,
+    pub(crate) accepted: bool,
+    pub(crate) badge_claimed: bool,
+    /// Net amount of the entry fee that reached the reward pool for this entry, which is less
+    /// than the entry fee for mints with a transfer fee.
+    pub(crate) deposit: u64,
 }
 
 #[account]
@@ -16,61 +21,166 @@ pub(crate) struct Team {
     pub(crate) participants: Vec<ParticipantInfo>,
     pub(crate) team_size: u16,
     pub(crate) completed: bool,
+    pub(crate) invite_expiration: u64,
 }
 
 impl Team {
-    pub(crate) fn new(captain: Pubkey, team_size: u16) -> Self {
+    pub(crate) fn new(captain: Pubkey, team_size: u16, invite_expiration: u64) -> Self {
         Self {
             captain,
             participants: vec![],
             team_size,
             completed: false,
+            invite_expiration,
         }
     }
 
-    pub(crate) fn add_participants_by_captain(&mut self, participants: Vec<Pubkey>) -> Result<()> {
-        if self.participants.len() + participants.len() > self.team_size as usize {
+    /// `deposit` is what the captain's payment added to the reward pool. It is split evenly
+    /// between the entries it paid for, with the remainder on the captain's own entry.
+    pub(crate) fn add_participants_by_captain(
+        &mut self,
+        teammates: Vec<Pubkey>,
+        prepaid: bool,
+        deposit: u64,
+    ) -> Result<()> {
+        if self.participants.len() + teammates.len() + 1 > self.team_size as usize {
             return Err(GenomeError::MaxPlayersExceeded.into());
         }
 
-        for participant in participants {
+        let paid_entries = if prepaid {
+            teammates.len() as u64 + 1
+        } else {
+            1
+        };
+        let share = deposit / paid_entries;
+        self.participants.push(ParticipantInfo {
+            pubkey: self.captain,
+            paid_by_captain: true,
+            claimed: false,
+            accepted: true,
+            badge_claimed: false,
+            deposit: deposit - share * (paid_entries - 1),
+        });
+
+        for teammate in teammates {
+            if self.participants.iter().any(|p| p.pubkey == teammate) {
+                return Err(GenomeError::AlreadyRegistered.into());
+            }
+
             self.participants.push(ParticipantInfo {
-                pubkey: participant,
-                paid_by_captain: true,
+                pubkey: teammate,
+                paid_by_captain: prepaid,
                 claimed: false,
+                accepted: false,
+                badge_claimed: false,
+                deposit: if prepaid { share } else { 0 },
             });
         }
 
-        if self.participants.len() == self.team_size as usize {
-            self.completed = true;
-        }
+        self.update_completed();
 
         Ok(())
     }
 
-    pub(crate) fn add_participant(&mut self, participant: Pubkey) -> Result<()> {
+    pub(crate) fn add_participant(&mut self, participant: Pubkey, deposit: u64) -> Result<()> {
         if self.participants.len() == self.team_size as usize {
             return Err(GenomeError::MaxPlayersExceeded.into());
         }
 
+        if self.participants.iter().any(|p| p.pubkey == participant) {
+            return Err(GenomeError::AlreadyRegistered.into());
+        }
+
         self.participants.push(ParticipantInfo {
             pubkey: participant,
             paid_by_captain: false,
             claimed: false,
+            accepted: true,
+            badge_claimed: false,
+            deposit,
         });
 
-        if self.participants.len() == self.team_size as usize {
-            self.completed = true;
+        self.update_completed();
+
+        Ok(())
+    }
+
+    /// Confirms a pending invite and returns whether the captain has prepaid the entry fee.
+    pub(crate) fn accept_invite(&mut self, participant: &Pubkey) -> Result<bool> {
+        let participant_info = self
+            .participants
+            .iter_mut()
+            .find(|p| p.pubkey == *participant)
+            .ok_or(GenomeError::ParticipantNotFound)?;
+
+        if participant_info.accepted {
+            return Err(GenomeError::AlreadyRegistered.into());
         }
+        participant_info.accepted = true;
+        let prepaid = participant_info.paid_by_captain;
 
+        self.update_completed();
+
+        Ok(prepaid)
+    }
+
+    /// Records the entry fee paid by a participant who accepted an invite on their own.
+    pub(crate) fn record_deposit(&mut self, participant: &Pubkey, deposit: u64) -> Result<()> {
+        let participant_info = self
+            .participants
+            .iter_mut()
+            .find(|p| p.pubkey == *participant)
+            .ok_or(GenomeError::ParticipantNotFound)?;
+        participant_info.deposit = deposit;
         Ok(())
     }
 
-    pub(crate) fn refund_participant(&mut self, participant: &Pubkey) -> Result<usize> {
+    /// Drops all pending invites and returns the deposits the captain has prepaid for them.
+    pub(crate) fn expire_invites(&mut self) -> u64 {
+        let prepaid = self
+            .participants
+            .iter()
+            .filter(|p| !p.accepted && p.paid_by_captain)
+            .map(|p| p.deposit)
+            .sum();
+        self.participants.retain(|p| p.accepted);
+        self.update_completed();
+        prepaid
+    }
+
+    /// Total of the entry fees held in the reward pool for this team.
+    pub(crate) fn deposits(&self) -> u64 {
+        self.participants.iter().map(|p| p.deposit).sum()
+    }
+
+    fn update_completed(&mut self) {
+        self.completed = self.participants.len() == self.team_size as usize
+            && self.participants.iter().all(|p| p.accepted);
+    }
+
+    pub(crate) fn remove_participant(&mut self, participant: &Pubkey) -> Result<ParticipantInfo> {
+        if *participant == self.captain {
+            return Err(GenomeError::NotAllowed.into());
+        }
+
+        let index = self
+            .participants
+            .iter()
+            .position(|p| p.pubkey == *participant)
+            .ok_or(GenomeError::ParticipantNotFound)?;
+
+        self.completed = false;
+        Ok(self.participants.remove(index))
+    }
+
+    /// Marks the participant as refunded and returns the amount owed to them. The captain is
+    /// refunded the deposits of every entry they paid for.
+    pub(crate) fn refund_participant(&mut self, participant: &Pubkey) -> Result<u64> {
         let participant_info = self
             .participants
             .iter_mut()
             .find(|p| p.pubkey == *participant)
+            .filter(|p| p.accepted)
             .ok_or(GenomeError::ParticipantNotFound)?;
 
         if participant_info.claimed {
@@ -79,22 +189,37 @@ impl Team {
         participant_info.claimed = true;
 
         if !participant_info.paid_by_captain {
-            return Ok(1);
+            return Ok(participant_info.deposit);
         }
 
         if *participant == self.captain {
-            let count = self.participants.iter().filter(|p| p.paid_by_captain).count();
-            return Ok(count);
+            let amount =
+                self.participants.iter().filter(|p| p.paid_by_captain).map(|p| p.deposit).sum();
+            return Ok(amount);
         }
 
         Ok(0)
     }
 
+    /// Whether every participant owed a refund has claimed it. Teammates prepaid by the
+    /// captain are refunded through the captain.
+    pub(crate) fn refunds_claimed(&self) -> bool {
+        self.participants
+            .iter()
+            .filter(|p| p.accepted)
+            .all(|p| p.claimed || (p.paid_by_captain && p.pubkey != self.captain))
+    }
+
+    pub(crate) fn rewards_claimed(&self) -> bool {
+        self.participants.iter().filter(|p| p.accepted).all(|p| p.claimed)
+    }
+
     pub(crate) fn reward_participant(&mut self, participant: &Pubkey) -> Result<()> {
         let participant_info = self
             .participants
             .iter_mut()
             .find(|p| p.pubkey == *participant)
+            .filter(|p| p.accepted)
             .ok_or(GenomeError::ParticipantNotFound)?;
 
         if participant_info.claimed {
@@ -105,4 +230,112 @@ impl Team {
 
         Ok(())
     }
+
+    pub(crate) fn claim_badge(&mut self, participant: &Pubkey) -> Result<()> {
+        require!(self.completed, GenomeError::InvalidTeam);
+        let participant_info = self
+            .participants
+            .iter_mut()
+            .find(|p| p.pubkey == *participant)
+            .filter(|p| p.accepted)
+            .ok_or(GenomeError::ParticipantNotFound)?;
+
+        if participant_info.badge_claimed {
+            return Err(GenomeError::AlreadyClaimed.into());
+        }
+
+        participant_info.badge_claimed = true;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_team_completed_after_invites_accepted() {
+        let captain = Pubkey::new_unique();
+        let teammate = Pubkey::new_unique();
+        let mut team = Team::new(captain, 2, 0);
+
+        team.add_participants_by_captain(vec![teammate], true, 300).unwrap();
+        assert!(!team.completed);
+
+        let prepaid = team.accept_invite(&teammate).unwrap();
+        assert!(prepaid);
+        assert!(team.completed);
+        assert!(team.accept_invite(&teammate).is_err());
+    }
+
+    #[test]
+    fn test_team_refunds_claimed_through_captain() {
+        let captain = Pubkey::new_unique();
+        let prepaid = Pubkey::new_unique();
+        let mut team = Team::new(captain, 3, 0);
+
+        team.add_participants_by_captain(vec![prepaid], true, 300).unwrap();
+        team.accept_invite(&prepaid).unwrap();
+        let self_paid = Pubkey::new_unique();
+        team.add_participant(self_paid, 150).unwrap();
+        assert!(!team.refunds_claimed());
+
+        assert_eq!(team.refund_participant(&captain).unwrap(), 300);
+        assert!(!team.refunds_claimed());
+        assert_eq!(team.refund_participant(&self_paid).unwrap(), 150);
+        assert!(team.refunds_claimed());
+        assert!(!team.rewards_claimed());
+    }
+
+    #[test]
+    fn test_team_expire_invites_refunds_prepaid_only() {
+        let captain = Pubkey::new_unique();
+        let prepaid_teammate = Pubkey::new_unique();
+        let unpaid_teammate = Pubkey::new_unique();
+        let mut team = Team::new(captain, 3, 0);
+
+        team.add_participants_by_captain(vec![prepaid_teammate], true, 299).unwrap();
+        team.participants.push(ParticipantInfo {
+            pubkey: unpaid_teammate,
+            paid_by_captain: false,
+            claimed: false,
+            accepted: false,
+            badge_claimed: false,
+            deposit: 0,
+        });
+
+        assert_eq!(team.expire_invites(), 149);
+        assert_eq!(team.deposits(), 150);
+        assert_eq!(team.participants.len(), 1);
+        assert!(!team.completed);
+    }
+
+    #[test]
+    fn test_team_pending_invite_cannot_claim_refund() {
+        let captain = Pubkey::new_unique();
+        let teammate = Pubkey::new_unique();
+        let mut team = Team::new(captain, 2, 0);
+
+        team.add_participants_by_captain(vec![teammate], false, 150).unwrap();
+
+        assert!(team.refund_participant(&teammate).is_err());
+        assert_eq!(team.refund_participant(&captain).unwrap(), 150);
+    }
+
+    #[test]
+    fn test_team_claim_badge() {
+        let captain = Pubkey::new_unique();
+        let teammate = Pubkey::new_unique();
+        let mut team = Team::new(captain, 2, 0);
+
+        team.add_participants_by_captain(vec![teammate], false, 150).unwrap();
+        assert!(team.claim_badge(&captain).is_err());
+
+        team.accept_invite(&teammate).unwrap();
+        team.claim_badge(&captain).unwrap();
+        assert!(team.claim_badge(&captain).is_err());
+        assert!(team.claim_badge(&Pubkey::new_unique()).is_err());
+        team.claim_badge(&teammate).unwrap();
+    }
 }
@@ -1,12 +1,21 @@
 use anchor_lang::{
-    prelude::{msg, Account, Clock, Result, SolanaSysvar},
+    prelude::{msg, Account, AccountInfo, Clock, Pubkey, Result, SolanaSysvar, ToAccountInfo},
     require,
+    solana_program::{hash::hashv, pubkey::PUBKEY_BYTES},
 };
+use anchor_spl::token::spl_token::native_mint;
 use growable_bloom_filter::GrowableBloom as Bloom;
 
 use crate::{
-    data::{BloomFilter, GenomeSingleConfig, TokenInfo, TournamentConfig},
+    bracket::Bracket,
+    data::{
+        BloomFilter, GenomeSingleConfig, TokenInfo, TournamentConfig, TournamentFormat,
+        TournamentMetadata,
+    },
     error::GenomeError,
+    fees::BPS,
+    league::League,
+    realloc,
 };
 
 const MAX_MEMORY: usize = 8156;
@@ -45,6 +54,64 @@ pub fn validate_params(
         params.min_teams >= config.min_teams && params.max_teams <= config.max_teams,
         GenomeError::InvalidTeamsCount
     );
+    require!(
+        params.format != TournamentFormat::SingleElimination
+            || params.max_teams as usize <= Bracket::MAX_TEAMS,
+        GenomeError::InvalidTeamsCount
+    );
+    require!(
+        params.format != TournamentFormat::League || params.max_teams as usize <= League::MAX_TEAMS,
+        GenomeError::InvalidTeamsCount
+    );
+    require!(
+        params.tiebreakers.len() <= League::MAX_TIEBREAKERS
+            && League::validate_tiebreakers(&params.tiebreakers),
+        GenomeError::InvalidTiebreakers
+    );
+    require!(
+        params
+            .claim_period
+            .is_none_or(|period| period > 0 && period <= TournamentConfig::MAX_CLAIM_PERIOD),
+        GenomeError::InvalidClaimPeriod
+    );
+    require!(!params.native_sol || params.asset_mint == native_mint::ID, GenomeError::InvalidToken);
+    validate_prize_distribution(params)?;
+    Ok(())
+}
+
+fn validate_prize_distribution(params: &TournamentConfig) -> Result<()> {
+    let places = params.prize_distribution.len();
+    require!(
+        places > 0 && places <= TournamentConfig::MAX_PRIZE_PLACES,
+        GenomeError::InvalidPrizeDistribution
+    );
+    require!(places <= params.min_teams as usize, GenomeError::InvalidPrizeDistribution);
+
+    let total: u64 = params.prize_distribution.iter().map(|&share| share as u64).sum();
+    require!(total == BPS, GenomeError::InvalidPrizeDistribution);
+
+    if params.format == TournamentFormat::SingleElimination {
+        require!(
+            Bracket::validate_prize_distribution(&params.prize_distribution),
+            GenomeError::InvalidPrizeDistribution
+        );
+    }
+    Ok(())
+}
+
+pub fn validate_metadata(metadata: &TournamentMetadata) -> Result<()> {
+    require!(
+        !metadata.name.is_empty() && metadata.name.len() <= TournamentMetadata::MAX_NAME_LEN,
+        GenomeError::InvalidMetadata
+    );
+    require!(
+        !metadata.game.is_empty() && metadata.game.len() <= TournamentMetadata::MAX_GAME_LEN,
+        GenomeError::InvalidMetadata
+    );
+    require!(
+        metadata.rules_uri.len() <= TournamentMetadata::MAX_RULES_URI_LEN,
+        GenomeError::InvalidMetadata
+    );
     Ok(())
 }
 
@@ -59,3 +126,105 @@ pub fn initialize_bloom_filter(
     bloom_filter.data = bincode::serialize(&bloom).expect("Failed to serialize bloom filter");
     Ok(())
 }
+
+/// Inserts the participant into the Bloom filter. A participant which is already
+/// present in the filter can only register again if they have left a team before.
+pub fn take_bloom_slot(
+    participant: &Pubkey,
+    bloom: &mut Bloom,
+    released: &mut Vec<Pubkey>,
+) -> Result<()> {
+    if bloom.insert(participant) {
+        return Ok(());
+    }
+
+    let index =
+        released.iter().position(|p| p == participant).ok_or(GenomeError::AlreadyRegistered)?;
+    released.swap_remove(index);
+
+    Ok(())
+}
+
+pub fn release_bloom_slots<'info>(
+    bloom_filter: &mut Account<'info, BloomFilter>,
+    payer: AccountInfo<'info>,
+    participants: &[Pubkey],
+) -> Result<()> {
+    let bloom_filter_info = bloom_filter.to_account_info();
+    let new_space = bloom_filter_info.data_len() + participants.len() * PUBKEY_BYTES;
+    realloc(bloom_filter_info, payer, new_space)?;
+
+    bloom_filter.released.extend_from_slice(participants);
+    Ok(())
+}
+
+pub fn check_allowlist(
+    allowlist_root: &Option<[u8; 32]>,
+    participants: &[Pubkey],
+    proofs: &[Vec<[u8; 32]>],
+) -> Result<()> {
+    let Some(root) = allowlist_root else {
+        return Ok(());
+    };
+
+    require!(participants.len() == proofs.len(), GenomeError::InvalidAllowlistProof);
+    for (participant, proof) in participants.iter().zip(proofs) {
+        require!(verify_merkle_proof(root, participant, proof), GenomeError::InvalidAllowlistProof);
+    }
+
+    Ok(())
+}
+
+/// Leaves are `sha256(pubkey)` and every level hashes the sorted pair of nodes.
+fn verify_merkle_proof(root: &[u8; 32], participant: &Pubkey, proof: &[[u8; 32]]) -> bool {
+    let mut node = hashv(&[participant.as_ref()]).to_bytes();
+    for sibling in proof {
+        node = if node <= *sibling {
+            hashv(&[&node, sibling]).to_bytes()
+        } else {
+            hashv(&[sibling, &node]).to_bytes()
+        };
+    }
+    node == *root
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hash_pair(a: [u8; 32], b: [u8; 32]) -> [u8; 32] {
+        if a <= b {
+            hashv(&[&a, &b]).to_bytes()
+        } else {
+            hashv(&[&b, &a]).to_bytes()
+        }
+    }
+
+    #[test]
+    fn test_merkle_proof_verification() {
+        let players: Vec<Pubkey> = (0..3).map(|_| Pubkey::new_unique()).collect();
+        let leaves: Vec<[u8; 32]> =
+            players.iter().map(|p| hashv(&[p.as_ref()]).to_bytes()).collect();
+        let left = hash_pair(leaves[0], leaves[1]);
+        let root = hash_pair(left, leaves[2]);
+
+        assert!(verify_merkle_proof(&root, &players[0], &[leaves[1], leaves[2]]));
+        assert!(verify_merkle_proof(&root, &players[2], &[left]));
+        assert!(!verify_merkle_proof(&root, &Pubkey::new_unique(), &[leaves[1], leaves[2]]));
+    }
+
+    #[test]
+    fn test_allowlist_disabled_without_root() {
+        assert!(check_allowlist(&None, &[Pubkey::new_unique()], &[]).is_ok());
+        assert!(check_allowlist(&Some([0; 32]), &[Pubkey::new_unique()], &[]).is_err());
+    }
+
+    #[test]
+    fn test_bloom_memory_follows_capacity() {
+        let small = calculate_bloom_memory(20, 65).unwrap();
+        let large = calculate_bloom_memory(40, 65).unwrap();
+        assert!(large > small);
+        assert_eq!(calculate_bloom_memory(20, 65).unwrap(), small);
+        assert!(calculate_bloom_memory(u16::MAX, 65).is_err());
+    }
+}
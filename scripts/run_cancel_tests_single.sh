@@ -9,4 +9,10 @@ done
 
 yarn run ts-mocha -p ./tsconfig.json -t 1000000 tests/Single/genomeSingle.test.ts --grep 'required'
 
+yarn run ts-mocha -p ./tsconfig.json -t 1000000 tests/Single/expireTournament.test.ts --grep 'required'
+
+yarn run ts-mocha -p ./tsconfig.json -t 1000000 tests/Single/organizerCancel.test.ts --grep 'required'
+
+yarn run ts-mocha -p ./tsconfig.json -t 1000000 tests/Single/transferFee.test.ts --grep 'required'
+
 yarn run ts-mocha -p ./tsconfig.json -t 1000000 tests/Single/cancelTournament.test.ts --grep 'required'
\ No newline at end of file
@@ -9,4 +9,10 @@ done
 
 yarn run ts-mocha -p ./tsconfig.json -t 1000000 tests/Single/genomeSingle.test.ts
 
+yarn run ts-mocha -p ./tsconfig.json -t 1000000 tests/Single/expireTournament.test.ts
+
+yarn run ts-mocha -p ./tsconfig.json -t 1000000 tests/Single/organizerCancel.test.ts
+
+yarn run ts-mocha -p ./tsconfig.json -t 1000000 tests/Single/transferFee.test.ts
+
 yarn run ts-mocha -p ./tsconfig.json -t 1000000 tests/Single/cancelTournament.test.ts
\ No newline at end of file
@@ -11,4 +11,6 @@ yarn run ts-mocha -p ./tsconfig.json -t 1000000 tests/Single/genomeSingle.test.t
 
 yarn run ts-mocha -p ./tsconfig.json -t 1000000 tests/Single/startTournament.test.ts
 
-yarn run ts-mocha -p ./tsconfig.json -t 1000000 tests/Single/finishTournament.test.ts
\ No newline at end of file
+yarn run ts-mocha -p ./tsconfig.json -t 1000000 tests/Single/finishTournament.test.ts
+
+yarn run ts-mocha -p ./tsconfig.json -t 1000000 tests/Single/staleTournament.test.ts
\ No newline at end of file
@@ -5,7 +5,7 @@ import { describe, it } from "mocha";
 
 import { IxBuilder } from "../../common/ixBuilder";
 import { getKeyPairs, checkAnchorError, MARKS } from "../utils";
-import { getTournament, buildAndSendTx, getProvider, getAtaInfo, Role, getRoleInfo, getSingleConfig, getGenomePda, GENOME_SINGLE_CONFIG, PLATFORM, GenomeSingleConfig } from "../../common/utils";
+import { getTournament, getSponsorship, buildAndSendTx, getProvider, getAtaInfo, Role, getRoleInfo, getSingleConfig, getGenomePda, GENOME_SINGLE_CONFIG, PLATFORM, GenomeSingleConfig } from "../../common/utils";
 
 describe("Cancel Tournament", () => {
     const tournamentId = 0;
@@ -126,6 +126,34 @@ describe("Cancel Tournament", () => {
         const claimerAtaAfter = await getAtaInfo(assetMint.publicKey, sponsor.publicKey);
         const tournament = await getTournament(tournamentId);
         assert.equal(claimerAtaAfter.amount - claimerAtaBefore.amount, tournament.config.sponsorPool);
+
+        const sponsorship = await getSponsorship(tournamentId, sponsor.publicKey);
+        assert.ok(sponsorship.refunded);
+    });
+
+    it(`Claim refund by sponsor twice [${MARKS.negative}]`, async () => {
+        try {
+            const claimRefundIx = await ixBuilder.claimSponsorRefundIx(sponsor.publicKey, tournamentId);
+            await buildAndSendTx([claimRefundIx], [sponsor]);
+            assert.fail("Sponsor refund was claimed twice");
+        } catch (error) {
+            checkAnchorError(error, "Paricipant already claimed");
+        }
+    });
+
+    it(`Close refunded sponsorship [${MARKS.required}]`, async () => {
+        const closeIx = await ixBuilder.closeSponsorshipIx(tournamentId, sponsor.publicKey);
+        const txSig = await buildAndSendTx([closeIx], [sponsor]);
+        console.log("Close sponsorship tx:", txSig);
+
+        try {
+            await getSponsorship(tournamentId, sponsor.publicKey);
+            assert.fail("Sponsorship was not closed");
+        } catch (error) {
+            checkAnchorError(error, "Account does not exist");
+        }
+        const tournament = await getTournament(tournamentId);
+        assert.equal(tournament.sponsorships, 0);
     });
 
     it(`Revoke Role [${MARKS.required}]`, async () => {
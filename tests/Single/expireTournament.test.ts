@@ -0,0 +1,207 @@
+import { Keypair } from "@solana/web3.js";
+import { BN } from "@coral-xyz/anchor";
+import * as assert from "assert";
+import { describe, it } from "mocha";
+
+import { IxBuilder } from "../../common/ixBuilder";
+import { getKeyPairs, checkAnchorError, getChainTime, waitForChainTime, MARKS } from "../utils";
+import { getTournament, airdropAll, buildAndSendTx, getAtaInfo, getGenomePda, getRoleInfo, getSingleConfig, GenomeSingleConfig, TournamentConfig, PLATFORM } from "../../common/utils";
+
+describe("Expire Tournament", () => {
+    let tournamentId: number;
+    let ixBuilder: IxBuilder;
+    let organizer: Keypair,
+        sponsor: Keypair,
+        captain: Keypair,
+        participant: Keypair,
+        attacker: Keypair,
+        assetMint: Keypair;
+
+    let singleConfig: GenomeSingleConfig;
+    let tournamentConfig: TournamentConfig;
+
+    before(async () => {
+        const keys = await getKeyPairs();
+        ixBuilder = new IxBuilder();
+        organizer = keys.organizer;
+        sponsor = keys.sponsor;
+        captain = keys.captain2;
+        participant = keys.participant2;
+        attacker = keys.attacker;
+        assetMint = keys.token;
+        await airdropAll([attacker.publicKey], 1);
+
+        singleConfig = await getSingleConfig();
+        tournamentId = singleConfig.tournamentNonce;
+        tournamentConfig = {
+            organizerFee: new BN(1000),
+            expirationTime: new BN((await getChainTime()) + 5),
+            sponsorPool: new BN(1000),
+            sponsor: sponsor.publicKey,
+            entryFee: new BN(150),
+            teamSize: 2,
+            minTeams: 2,
+            maxTeams: 10,
+            assetMint: assetMint.publicKey,
+            prizeDistribution: [10000],
+            allowlistRoot: null,
+            format: { freeForm: {} },
+            tiebreakers: [],
+            claimPeriod: new BN(15),
+            nativeSol: false,
+        };
+    });
+
+    it(`Create a tournament with a short expiration [${MARKS.required}]`, async () => {
+        const createIx = await ixBuilder.createTournamentIx(
+            organizer.publicKey,
+            sponsor.publicKey,
+            assetMint.publicKey,
+            tournamentConfig,
+            {
+                name: "Genome Sprint",
+                game: "dota2",
+                rulesUri: "",
+                rulesHash: Array(32).fill(0),
+            }
+        );
+        let txSig = await buildAndSendTx([createIx], [organizer]);
+        console.log("Create tournament tx:", txSig);
+
+        for (const member of [captain, participant]) {
+            const registerIx = await ixBuilder.registerTournamentIx({
+                tournamentId,
+                participant: member.publicKey,
+                captain: captain.publicKey,
+                teammates: [],
+                prepay: false,
+                inviteExpiration: new BN(0),
+                proofs: [],
+            });
+            txSig = await buildAndSendTx([registerIx], [member]);
+            console.log("Register tournament tx:", txSig);
+        }
+
+        const tournament = await getTournament(tournamentId);
+        assert.ok(tournament.status.new);
+        assert.equal(tournament.teamCount, 1);
+        const entryFees = tournamentConfig.entryFee.toNumber() * 2;
+        assert.equal(tournament.entryFees.toNumber(), entryFees);
+        assert.equal(tournament.totalDeposited.toNumber(), tournamentConfig.sponsorPool.toNumber() + entryFees);
+    });
+
+    it(`Expire tournament before the expiration time [${MARKS.negative}]`, async () => {
+        const expireIx = await ixBuilder.expireTournamentIx(attacker.publicKey, tournamentId);
+        try {
+            await buildAndSendTx([expireIx], [attacker]);
+            throw new Error("Expected error for premature expiry");
+        } catch (error) {
+            checkAnchorError(error, "Tournament has not expired yet");
+        }
+    });
+
+    it(`Organizer cancel with registrations after the expiration time [${MARKS.negative}]`, async () => {
+        await waitForChainTime(tournamentConfig.expirationTime.toNumber());
+
+        const cancelIx = await ixBuilder.organizerCancelIx(organizer.publicKey, tournamentId);
+        try {
+            await buildAndSendTx([cancelIx], [organizer]);
+            throw new Error("Expected error for late organizer cancel");
+        } catch (error) {
+            checkAnchorError(error, "Tournament already has registrations");
+        }
+    });
+
+    it(`Expire tournament by anyone [${MARKS.required}]`, async () => {
+        await waitForChainTime(tournamentConfig.expirationTime.toNumber());
+
+        const roleInfoBefore = await getRoleInfo(organizer.publicKey);
+        const expireIx = await ixBuilder.expireTournamentIx(attacker.publicKey, tournamentId);
+        const txSig = await buildAndSendTx([expireIx], [attacker]);
+        console.log("Expire tournament tx:", txSig);
+
+        const tournament = await getTournament(tournamentId);
+        assert.ok(tournament.status.canceled, "Tournament should be canceled");
+        const roleInfoAfter = await getRoleInfo(organizer.publicKey);
+        assert.equal(roleInfoAfter.claim.sub(roleInfoBefore.claim).toNumber(), singleConfig.platformFee.toNumber());
+    });
+
+    it(`Expire tournament twice [${MARKS.negative}]`, async () => {
+        const expireIx = await ixBuilder.expireTournamentIx(attacker.publicKey, tournamentId);
+        try {
+            await buildAndSendTx([expireIx], [attacker]);
+            throw new Error("Expected error for second expiry");
+        } catch (error) {
+            checkAnchorError(error, "Invalid tournament status");
+        }
+    });
+
+    it(`Claim refund after expiry [${MARKS.required}]`, async () => {
+        const claimerAtaBefore = await getAtaInfo(assetMint.publicKey, captain.publicKey);
+        const claimRefundIx = await ixBuilder.claimRefundIx(captain.publicKey, tournamentId, captain.publicKey);
+        const txSig = await buildAndSendTx([claimRefundIx], [captain]);
+        console.log("Claim refund tx (captain):", txSig);
+
+        const claimerAtaAfter = await getAtaInfo(assetMint.publicKey, captain.publicKey);
+        assert.equal(claimerAtaAfter.amount - claimerAtaBefore.amount, tournamentConfig.entryFee.toNumber());
+        const tournament = await getTournament(tournamentId);
+        assert.equal(tournament.refundsPaid.toNumber(), tournamentConfig.entryFee.toNumber());
+    });
+
+    it(`Claim sponsor refund after expiry [${MARKS.required}]`, async () => {
+        const sponsorAtaBefore = await getAtaInfo(assetMint.publicKey, sponsor.publicKey);
+        const claimRefundIx = await ixBuilder.claimSponsorRefundIx(sponsor.publicKey, tournamentId);
+        const txSig = await buildAndSendTx([claimRefundIx], [sponsor]);
+        console.log("Claim refund tx (sponsor):", txSig);
+
+        const sponsorAtaAfter = await getAtaInfo(assetMint.publicKey, sponsor.publicKey);
+        assert.equal(sponsorAtaAfter.amount - sponsorAtaBefore.amount, tournamentConfig.sponsorPool.toNumber());
+        const tournament = await getTournament(tournamentId);
+        assert.equal(
+            tournament.refundsPaid.toNumber(),
+            tournamentConfig.entryFee.add(tournamentConfig.sponsorPool).toNumber()
+        );
+    });
+
+    it(`Sweep unclaimed before the claim period is over [${MARKS.negative}]`, async () => {
+        const sweepIx = await ixBuilder.sweepUnclaimedIx(attacker.publicKey, tournamentId);
+        try {
+            await buildAndSendTx([sweepIx], [attacker]);
+            throw new Error("Expected error for premature sweep");
+        } catch (error) {
+            checkAnchorError(error, "Claim period is not over yet");
+        }
+    });
+
+    it(`Sweep unclaimed refunds [${MARKS.required}]`, async () => {
+        let tournament = await getTournament(tournamentId);
+        await waitForChainTime(tournament.claimDeadline.toNumber());
+
+        const platformPda = await getGenomePda([PLATFORM]);
+        const platformAtaBefore = await getAtaInfo(assetMint.publicKey, platformPda).catch(() => null);
+        const sweepIx = await ixBuilder.sweepUnclaimedIx(attacker.publicKey, tournamentId);
+        const txSig = await buildAndSendTx([sweepIx], [attacker]);
+        console.log("Sweep unclaimed tx:", txSig);
+
+        const platformAtaAfter = await getAtaInfo(assetMint.publicKey, platformPda);
+        const swept = platformAtaAfter.amount - (platformAtaBefore?.amount ?? BigInt(0));
+        assert.equal(swept, tournamentConfig.entryFee.toNumber());
+
+        tournament = await getTournament(tournamentId);
+        assert.equal(tournament.unclaimedSwept.toNumber(), tournamentConfig.entryFee.toNumber());
+        assert.equal(
+            tournament.totalDeposited.toNumber(),
+            tournament.refundsPaid.add(tournament.unclaimedSwept).toNumber()
+        );
+    });
+
+    it(`Claim refund after the claim period [${MARKS.negative}]`, async () => {
+        const claimRefundIx = await ixBuilder.claimRefundIx(participant.publicKey, tournamentId, captain.publicKey);
+        try {
+            await buildAndSendTx([claimRefundIx], [participant]);
+            throw new Error("Expected error for late refund");
+        } catch (error) {
+            checkAnchorError(error, "Claim period is over");
+        }
+    });
+});
@@ -1,11 +1,11 @@
 import { Keypair, PublicKey } from "@solana/web3.js";
-import { Account as SplTokenAccount } from "@solana/spl-token";
+import { Account as SplTokenAccount, getAccount, getAssociatedTokenAddress, TOKEN_2022_PROGRAM_ID } from "@solana/spl-token";
 import * as assert from "assert";
 import { describe, it } from "mocha";
 
 import { IxBuilder } from "../../common/ixBuilder";
 import { getKeyPairs, checkAnchorError, MARKS } from "../utils";
-import { getTournament, buildAndSendTx, getAtaInfo, getRoleInfo, getSingleConfig, getGenomePda, GENOME_SINGLE_CONFIG, PLATFORM, GenomeSingleConfig, getFinishInfo } from "../../common/utils";
+import { getTournament, buildAndSendTx, getAtaInfo, getRoleInfo, getSingleConfig, getGenomePda, GENOME_SINGLE_CONFIG, PLATFORM, GenomeSingleConfig, getFinishInfo, getTeam, TROPHY, getProvider } from "../../common/utils";
 
 describe("Finish Tournament", () => {
     const tournamentId = 0;
@@ -49,16 +49,16 @@ describe("Finish Tournament", () => {
 
   it(`Verifier vote [${MARKS.required}]`, async () => {
     const roleInfoBefore = await getRoleInfo(verifier1.publicKey);
-    const startIx = await ixBuilder.finishTournamentIx(verifier1.publicKey, tournamentId, captain.publicKey);
+    const startIx = await ixBuilder.finishTournamentIx(verifier1.publicKey, tournamentId, [captain.publicKey]);
     const txSig = await buildAndSendTx([startIx], [verifier1]);
     console.log("Finish tournament tx (verifier1):", txSig);
 
     const roleInfoAfter = await getRoleInfo(verifier1.publicKey);
-    assert.equal(roleInfoAfter.claim.sub(roleInfoBefore.claim).toNumber(), singleConfig.verifierFee.toNumber());
+    assert.equal(roleInfoAfter.claim.sub(roleInfoBefore.claim).toNumber(), 0);
   });
 
   it(`Verifier vote second time [${MARKS.negative}]`, async () => {
-    const finishIx = await ixBuilder.finishTournamentIx(verifier1.publicKey, tournamentId, captain.publicKey);
+    const finishIx = await ixBuilder.finishTournamentIx(verifier1.publicKey, tournamentId, [captain.publicKey]);
     try {
       await buildAndSendTx([finishIx], [verifier1]);
       throw new Error("Expected error for second voting");
@@ -78,7 +78,7 @@ describe("Finish Tournament", () => {
   });
 
   it(`Finish tournament [${MARKS.required}]`, async () => {
-    const finishIx = await ixBuilder.finishTournamentIx(verifier2.publicKey, tournamentId, captain.publicKey);
+    const finishIx = await ixBuilder.finishTournamentIx(verifier2.publicKey, tournamentId, [captain.publicKey]);
     const txSig = await buildAndSendTx([finishIx], [verifier2]);
     console.log("Finish tournament tx (verifier2):", txSig);
 
@@ -87,6 +87,36 @@ describe("Finish Tournament", () => {
     console.log("Tournament finished");
   });
 
+  it(`Organizer fee takes the rounding remainder [${MARKS.required}]`, async () => {
+    const tournament = await getTournament(tournamentId);
+    const finishMetaData = await getFinishInfo(tournamentId);
+    const rewardPool = tournament.sponsorPool.add(tournament.entryPool).toNumber();
+    const rewards = finishMetaData.rewards.reduce((sum, reward) => sum + reward.toNumber() * tournament.config.teamSize, 0);
+
+    const organizerAtaAfter = await getAtaInfo(assetMint.publicKey, organizer.publicKey);
+    assert.equal(organizerAtaAfter.amount - organizerAtaBefore.amount, rewardPool - rewards);
+    assert.equal(tournament.organizerFeePaid.toNumber(), rewardPool - rewards);
+    assert.ok(tournament.organizerFeePaid.toNumber() >= rewardPool * tournament.config.organizerFee.toNumber() / 10000);
+  });
+
+  it(`Settle verifiers who backed the winner [${MARKS.required}]`, async () => {
+    for (const verifier of [verifier1, verifier2]) {
+      const roleInfoBefore = await getRoleInfo(verifier.publicKey);
+      const settleIx = await ixBuilder.settleVerifierIx(verifier.publicKey, tournamentId, verifier.publicKey);
+      const txSig = await buildAndSendTx([settleIx], [verifier]);
+      console.log("Settle verifier tx:", txSig);
+
+      const roleInfoAfter = await getRoleInfo(verifier.publicKey);
+      assert.equal(
+        roleInfoAfter.claim.toNumber() - roleInfoBefore.claim.toNumber(),
+        singleConfig.verifierFee.toNumber()
+      );
+    }
+
+    const finishMetaData = await getFinishInfo(tournamentId);
+    assert.equal(finishMetaData.settled.toNumber(), 0b11);
+  });
+
   it(`Claim reward by participant [${MARKS.required}]`, async () => {
     const claimerAtaBefore = await getAtaInfo(assetMint.publicKey, participant.publicKey);
     const claimRewardIx = await ixBuilder.claimRewardIx(participant.publicKey, tournamentId, captain.publicKey);
@@ -95,12 +125,52 @@ describe("Finish Tournament", () => {
 
     const claimerAtaAfter = await getAtaInfo(assetMint.publicKey, participant.publicKey);
     const finishMetaData = await getFinishInfo(tournamentId);
-    assert.equal(claimerAtaAfter.amount - claimerAtaBefore.amount, finishMetaData.reward.toNumber());
+    assert.equal(claimerAtaAfter.amount - claimerAtaBefore.amount, finishMetaData.rewards[0].toNumber());
+  });
+
+  it(`Trophy is minted to the claimer [${MARKS.required}]`, async () => {
+    const idBuffer = Buffer.alloc(4);
+    idBuffer.writeUInt32LE(tournamentId, 0);
+    const trophyMint = await getGenomePda([TROPHY, idBuffer, captain.publicKey.toBuffer()]);
+    const trophyAta = await getAssociatedTokenAddress(trophyMint, participant.publicKey, true, TOKEN_2022_PROGRAM_ID);
+    const trophy = await getAccount(getProvider().connection, trophyAta, undefined, TOKEN_2022_PROGRAM_ID);
+    assert.equal(trophy.amount, BigInt(1));
   });
 
   it(`Check organizer reward [${MARKS.required}]`, async () => {
     const organizerAtaAfter = await getAtaInfo(assetMint.publicKey, participant.publicKey);
     const finishMetaData = await getFinishInfo(tournamentId);
-    assert.equal(organizerAtaAfter.amount - organizerAtaBefore.amount, finishMetaData.reward.toNumber());
+    assert.equal(organizerAtaAfter.amount - organizerAtaBefore.amount, finishMetaData.rewards[0].toNumber());
+  });
+
+  it(`Distribute rewards to the rest of the team [${MARKS.required}]`, async () => {
+    const captainAtaBefore = await getAtaInfo(assetMint.publicKey, captain.publicKey);
+    const distributeIx = await ixBuilder.distributeRewardsIx(organizer.publicKey, tournamentId, captain.publicKey);
+    const txSig = await buildAndSendTx([distributeIx], [organizer]);
+    console.log("Distribute rewards tx:", txSig);
+
+    const captainAtaAfter = await getAtaInfo(assetMint.publicKey, captain.publicKey);
+    const finishMetaData = await getFinishInfo(tournamentId);
+    assert.equal(captainAtaAfter.amount - captainAtaBefore.amount, finishMetaData.rewards[0].toNumber());
+
+    const team = await getTeam(tournamentId, captain.publicKey);
+    assert.ok(team.participants.every((p) => p.claimed));
+
+    const idBuffer = Buffer.alloc(4);
+    idBuffer.writeUInt32LE(tournamentId, 0);
+    const trophyMint = await getGenomePda([TROPHY, idBuffer, captain.publicKey.toBuffer()]);
+    const trophyAta = await getAssociatedTokenAddress(trophyMint, captain.publicKey, true, TOKEN_2022_PROGRAM_ID);
+    const trophy = await getAccount(getProvider().connection, trophyAta, undefined, TOKEN_2022_PROGRAM_ID);
+    assert.equal(trophy.amount, BigInt(1));
+  });
+
+  it(`Claim reward after distribution [${MARKS.negative}]`, async () => {
+    const claimRewardIx = await ixBuilder.claimRewardIx(captain.publicKey, tournamentId, captain.publicKey);
+    try {
+      await buildAndSendTx([claimRewardIx], [captain]);
+      throw new Error("Expected error for claim after distribution");
+    } catch (error) {
+      checkAnchorError(error, "Paricipant already claimed");
+    }
   });
 });
@@ -19,15 +19,18 @@ import {
     buildAndSendTx,
     getTokenInfo,
     getTournament,
+    getTournamentMetadata,
     getProvider,
     getGenomePda,
     GENOME_SINGLE_CONFIG,
     getAtaInfo,
     PLATFORM,
     TournamentConfig,
+    TournamentMetadata,
     GenomeSingleConfig,
     getRoleInfo,
     TOURNAMENT,
+    BLOOM,
     Role,
 } from "../../common/utils";
 
@@ -55,6 +58,7 @@ describe("Genome Solana Singlechain", () => {
         platformPda: PublicKey;
 
     let tournamentConfigMock: TournamentConfig;
+    let tournamentMetadataMock: TournamentMetadata;
     let configData: GenomeSingleConfig;
 
     before(async () => {
@@ -68,9 +72,22 @@ describe("Genome Solana Singlechain", () => {
             sponsor: sponsor.publicKey,
             entryFee: new BN(150),
             teamSize: 2,
-            minTeams: 4,
+            minTeams: 2,
             maxTeams: 10,
             assetMint: token.publicKey,
+            prizeDistribution: [10000],
+            allowlistRoot: null,
+            format: { freeForm: {} },
+            tiebreakers: [],
+            claimPeriod: null,
+            nativeSol: false,
+        };
+
+        tournamentMetadataMock = {
+            name: "Genome Cup",
+            game: "dota2",
+            rulesUri: "https://genome.gg/rules/genome-cup.md",
+            rulesHash: Array(32).fill(0),
         };
 
         configData = {
@@ -85,6 +102,11 @@ describe("Genome Solana Singlechain", () => {
             falsePrecision: new BN(65), //  1000000
             maxOrganizerFee: new BN(5000), // 100
             consensusRate: new BN(6000), // 100
+            organizerCancelPenalty: new BN(5000), // 100
+            claimPeriod: new BN(30 * 24 * 60 * 60), // 30 days
+            stakeLockPeriod: new BN(7 * 24 * 60 * 60), // 7 days
+            totalStake: new BN(0),
+            verifierSlashRate: new BN(1000), // 10%
         };
 
         await airdropAll(
@@ -188,6 +210,18 @@ describe("Genome Solana Singlechain", () => {
         const config = await getSingleConfig();
     });
 
+    it(`Stake NOME [${MARKS.required}]`, async () => {
+        const amount = new BN(1000);
+        for (const verifier of [verifier1, verifier2, verifier3]) {
+            const stakeIx = await ixBuilder.stakeIx(verifier.publicKey, amount);
+            const txSig = await buildAndSendTx([stakeIx], [verifier]);
+            console.log("Stake tx signature:", txSig);
+        }
+
+        const config = await getSingleConfig();
+        assert.equal(config.totalStake.toNumber(), amount.toNumber() * 3);
+    });
+
     it(`Give the role to the same person again [${MARKS.negative}]`, async () => {
         await sleep(4000);
         const grantIx = await ixBuilder.grantRoleIx(
@@ -241,7 +275,8 @@ describe("Genome Solana Singlechain", () => {
                 organizer.publicKey,
                 sponsor.publicKey,
                 token.publicKey,
-                tournamentConfigMock
+                tournamentConfigMock,
+                tournamentMetadataMock
             );
             await buildAndSendTx([ix], [organizer]);
         } catch (error) {
@@ -270,7 +305,8 @@ describe("Genome Solana Singlechain", () => {
             organizer.publicKey,
             sponsor.publicKey,
             assetMint,
-            tournamentConfigMock
+            tournamentConfigMock,
+            tournamentMetadataMock
         );
         txSig = await buildAndSendTx([ix], [organizer]);
         console.log("Create Tournament tx signature:", txSig);
@@ -297,6 +333,11 @@ describe("Genome Solana Singlechain", () => {
         assert.equal(tournamentAccount.config.teamSize, tournamentConfigMock.teamSize);
         assert.equal(tournamentAccount.config.minTeams, tournamentConfigMock.minTeams);
         assert.equal(tournamentAccount.config.maxTeams, tournamentConfigMock.maxTeams);
+
+        const metadata = await getTournamentMetadata(configData.tournamentNonce - 1);
+        assert.equal(metadata.name, tournamentMetadataMock.name);
+        assert.equal(metadata.game, tournamentMetadataMock.game);
+        assert.equal(metadata.rulesUri, tournamentMetadataMock.rulesUri);
         assert.equal(organizerAtaBefore.amount - organizerAtaAfter.amount, configData.platformFee.toNumber());
         assert.equal(platformAtaAfter.amount - platformAtaBefore.amount, configData.platformFee.toNumber());
 
@@ -304,6 +345,42 @@ describe("Genome Solana Singlechain", () => {
         assert.equal(sponsorAtaBefore.amount - sponsorAtaAfter.amount, rewardPoolAta.amount);
     });
 
+    it(`Update tournament reallocates the Bloom filter [${MARKS.required}]`, async () => {
+        const idBuffer = Buffer.alloc(4);
+        idBuffer.writeUInt32LE(0, 0);
+        const bloomPda = await getGenomePda([BLOOM, idBuffer]);
+        const bloomBefore = await getProvider().connection.getAccountInfo(bloomPda);
+
+        let ix = await ixBuilder.updateTournamentIx(organizer.publicKey, 0, { ...tournamentConfigMock, maxTeams: 20 });
+        let txSig = await buildAndSendTx([ix], [organizer]);
+        console.log("Update tournament tx signature:", txSig);
+
+        let tournamentAccount = await getTournament(0);
+        const bloomGrown = await getProvider().connection.getAccountInfo(bloomPda);
+        assert.equal(tournamentAccount.config.maxTeams, 20);
+        assert.ok(bloomGrown.data.length > bloomBefore.data.length);
+
+        ix = await ixBuilder.updateTournamentIx(organizer.publicKey, 0, tournamentConfigMock);
+        txSig = await buildAndSendTx([ix], [organizer]);
+        console.log("Update tournament tx signature:", txSig);
+
+        tournamentAccount = await getTournament(0);
+        const bloomAfter = await getProvider().connection.getAccountInfo(bloomPda);
+        assert.equal(tournamentAccount.config.maxTeams, tournamentConfigMock.maxTeams);
+        assert.equal(bloomAfter.data.length, bloomBefore.data.length);
+    });
+
+    it(`Update tournament with a different sponsor pool [${MARKS.negative}]`, async () => {
+        try {
+            const invalidData = { ...tournamentConfigMock, sponsorPool: new BN(2000) };
+            const ix = await ixBuilder.updateTournamentIx(organizer.publicKey, 0, invalidData);
+            await buildAndSendTx([ix], [organizer]);
+            throw new Error("Expected error was not thrown");
+        } catch (error) {
+            checkAnchorError(error, "Asset mint, sponsor and sponsor pool can't be changed");
+        }
+    });
+
     it(`Register tournament - Captain + Teammates [${MARKS.required}]`, async () => {
         const tournamentAccount = await getTournament(0);
         const rewardPoolAtaBefore = await getAtaInfo(assetMint, tournamentPda);
@@ -311,12 +388,19 @@ describe("Genome Solana Singlechain", () => {
             tournamentId: 0,
             participant: captain1.publicKey,
             captain: captain1.publicKey,
-            teammates: [participant1.publicKey]
+            teammates: [participant1.publicKey],
+            prepay: true,
+            inviteExpiration: tournamentAccount.config.expirationTime,
+            proofs: [],
         }
 
         const registerIx = await ixBuilder.registerTournamentIx(registerParams);
         const txSig = await buildAndSendTx([registerIx], [captain1]);
         console.log("Register tournament tx signature:", txSig);
+
+        const acceptIx = await ixBuilder.acceptInviteIx(participant1.publicKey, 0, captain1.publicKey);
+        const acceptTxSig = await buildAndSendTx([acceptIx], [participant1]);
+        console.log("Accept invite tx signature:", acceptTxSig);
         const teamAccount = await getTeam(0, captain1.publicKey);
         assert.ok(teamAccount, "Team account not created");
         assert.equal(teamAccount.captain.toBase58(), registerParams.captain.toBase58());
@@ -331,7 +415,10 @@ describe("Genome Solana Singlechain", () => {
             tournamentId: 0,
             participant: participant1.publicKey,
             captain: captain1.publicKey,
-            teammates: []
+            teammates: [],
+            prepay: false,
+            inviteExpiration: new BN(0),
+            proofs: [],
         }
         try {
             const registerIx = await ixBuilder.registerTournamentIx(registerParams);
@@ -347,7 +434,10 @@ describe("Genome Solana Singlechain", () => {
             tournamentId: 0,
             participant: captain2.publicKey,
             captain: captain2.publicKey,
-            teammates: []
+            teammates: [],
+            prepay: false,
+            inviteExpiration: new BN(0),
+            proofs: [],
         }
 
         // Register new team
@@ -367,13 +457,24 @@ describe("Genome Solana Singlechain", () => {
         assert.equal(tournament.teamCount, 2);
     });
 
+    it(`Update tournament with registrations [${MARKS.negative}]`, async () => {
+        try {
+            const ix = await ixBuilder.updateTournamentIx(organizer.publicKey, 0, tournamentConfigMock);
+            await buildAndSendTx([ix], [organizer]);
+            throw new Error("Expected error was not thrown");
+        } catch (error) {
+            checkAnchorError(error, "Tournament already has registrations");
+        }
+    });
+
     it(`Create a Tournament by a non-organizer [${MARKS.negative}]`, async () => {
         try {
             const ix = await ixBuilder.createTournamentIx(
                 operator.publicKey,
                 sponsor.publicKey,
                 assetMint,
-                tournamentConfigMock
+                tournamentConfigMock,
+                tournamentMetadataMock
             );
             await buildAndSendTx([ix], [operator]);
         } catch (error) {
@@ -389,7 +490,8 @@ describe("Genome Solana Singlechain", () => {
                 organizer.publicKey,
                 sponsor.publicKey,
                 assetMint,
-                invalidData
+                invalidData,
+                tournamentMetadataMock
             );
             await buildAndSendTx([ix], [organizer]);
         } catch (error) {
@@ -404,7 +506,8 @@ describe("Genome Solana Singlechain", () => {
                 organizer.publicKey,
                 sponsor.publicKey,
                 assetMint,
-                invalidData
+                invalidData,
+                tournamentMetadataMock
             );
             await buildAndSendTx([ix], [organizer]);
         } catch (error) {
@@ -419,7 +522,8 @@ describe("Genome Solana Singlechain", () => {
                 organizer.publicKey,
                 sponsor.publicKey,
                 assetMint,
-                invalidData
+                invalidData,
+                tournamentMetadataMock
             );
             await buildAndSendTx([ix], [organizer]);
         } catch (error) {
@@ -432,7 +536,8 @@ describe("Genome Solana Singlechain", () => {
                 organizer.publicKey,
                 sponsor.publicKey,
                 assetMint,
-                invalidData
+                invalidData,
+                tournamentMetadataMock
             );
             await buildAndSendTx([ix], [organizer]);
         } catch (error) {
@@ -447,7 +552,8 @@ describe("Genome Solana Singlechain", () => {
                 organizer.publicKey,
                 sponsor.publicKey,
                 assetMint,
-                invalidData
+                invalidData,
+                tournamentMetadataMock
             );
             await buildAndSendTx([ix], [organizer]);
         } catch (error) {
@@ -462,7 +568,8 @@ describe("Genome Solana Singlechain", () => {
                 organizer.publicKey,
                 sponsor.publicKey,
                 assetMint,
-                invalidData
+                invalidData,
+                tournamentMetadataMock
             );
             await buildAndSendTx([ix], [organizer]);
         } catch (error) {
@@ -0,0 +1,108 @@
+import { Keypair } from "@solana/web3.js";
+import { BN } from "@coral-xyz/anchor";
+import * as assert from "assert";
+import { describe, it } from "mocha";
+
+import { IxBuilder } from "../../common/ixBuilder";
+import { getKeyPairs, checkAnchorError, getChainTime, MARKS } from "../utils";
+import { getTournament, getSponsorship, airdropAll, buildAndSendTx, getAtaInfo, getRoleInfo, getSingleConfig, GenomeSingleConfig, TournamentConfig } from "../../common/utils";
+
+describe("Organizer Cancel", () => {
+    let tournamentId: number;
+    let ixBuilder: IxBuilder;
+    let organizer: Keypair,
+        sponsor: Keypair,
+        attacker: Keypair,
+        assetMint: Keypair;
+
+    let singleConfig: GenomeSingleConfig;
+    let tournamentConfig: TournamentConfig;
+
+    before(async () => {
+        const keys = await getKeyPairs();
+        ixBuilder = new IxBuilder();
+        organizer = keys.organizer;
+        sponsor = keys.sponsor;
+        attacker = keys.attacker;
+        assetMint = keys.token;
+        await airdropAll([attacker.publicKey], 1);
+
+        singleConfig = await getSingleConfig();
+        tournamentId = singleConfig.tournamentNonce;
+        tournamentConfig = {
+            organizerFee: new BN(1000),
+            expirationTime: new BN((await getChainTime()) + 60 * 60),
+            sponsorPool: new BN(1000),
+            sponsor: sponsor.publicKey,
+            entryFee: new BN(150),
+            teamSize: 2,
+            minTeams: 2,
+            maxTeams: 10,
+            assetMint: assetMint.publicKey,
+            prizeDistribution: [10000],
+            allowlistRoot: null,
+            format: { freeForm: {} },
+            tiebreakers: [],
+            claimPeriod: null,
+            nativeSol: false,
+        };
+
+        const createIx = await ixBuilder.createTournamentIx(
+            organizer.publicKey,
+            sponsor.publicKey,
+            assetMint.publicKey,
+            tournamentConfig,
+            {
+                name: "Genome Open",
+                game: "dota2",
+                rulesUri: "",
+                rulesHash: Array(32).fill(0),
+            }
+        );
+        const txSig = await buildAndSendTx([createIx], [organizer]);
+        console.log("Create tournament tx:", txSig);
+    });
+
+    it(`Organizer cancel by non-organizer [${MARKS.negative}]`, async () => {
+        const cancelIx = await ixBuilder.organizerCancelIx(attacker.publicKey, tournamentId);
+        try {
+            await buildAndSendTx([cancelIx], [attacker]);
+            throw new Error("Expected error for non-organizer cancel");
+        } catch (error) {
+            checkAnchorError(error, "Not allowed");
+        }
+    });
+
+    it(`Organizer cancel [${MARKS.required}]`, async () => {
+        const sponsorAtaBefore = await getAtaInfo(assetMint.publicKey, sponsor.publicKey);
+        const roleInfoBefore = await getRoleInfo(organizer.publicKey);
+        const cancelIx = await ixBuilder.organizerCancelIx(organizer.publicKey, tournamentId);
+        const txSig = await buildAndSendTx([cancelIx], [organizer]);
+        console.log("Organizer cancel tx:", txSig);
+
+        const tournament = await getTournament(tournamentId);
+        assert.ok(tournament.status.canceled, "Tournament should be canceled");
+
+        const sponsorAtaAfter = await getAtaInfo(assetMint.publicKey, sponsor.publicKey);
+        assert.equal(sponsorAtaAfter.amount - sponsorAtaBefore.amount, tournamentConfig.sponsorPool.toNumber());
+        const sponsorship = await getSponsorship(tournamentId, sponsor.publicKey);
+        assert.ok(sponsorship.refunded);
+
+        const penalty = singleConfig.platformFee.mul(singleConfig.organizerCancelPenalty).div(new BN(10000));
+        const roleInfoAfter = await getRoleInfo(organizer.publicKey);
+        assert.equal(
+            roleInfoAfter.claim.sub(roleInfoBefore.claim).toNumber(),
+            singleConfig.platformFee.sub(penalty).toNumber()
+        );
+    });
+
+    it(`Organizer cancel twice [${MARKS.negative}]`, async () => {
+        const cancelIx = await ixBuilder.organizerCancelIx(organizer.publicKey, tournamentId);
+        try {
+            await buildAndSendTx([cancelIx], [organizer]);
+            throw new Error("Expected error for second cancel");
+        } catch (error) {
+            checkAnchorError(error, "Invalid tournament status");
+        }
+    });
+});
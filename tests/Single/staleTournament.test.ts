@@ -0,0 +1,154 @@
+import { Keypair } from "@solana/web3.js";
+import { BN } from "@coral-xyz/anchor";
+import * as assert from "assert";
+import { describe, it } from "mocha";
+
+import { IxBuilder } from "../../common/ixBuilder";
+import { getKeyPairs, checkAnchorError, getChainTime, waitForChainTime, MARKS } from "../utils";
+import { getTournament, buildAndSendTx, getRoleInfo, getSingleConfig, getFinishInfo, getVerifierStake, GenomeSingleConfig, TournamentConfig } from "../../common/utils";
+
+describe("Stale Tournament", () => {
+    let tournamentId: number;
+    let ixBuilder: IxBuilder;
+    let organizer: Keypair,
+        sponsor: Keypair,
+        captain1: Keypair,
+        captain2: Keypair,
+        verifier1: Keypair,
+        verifier2: Keypair,
+        verifier3: Keypair,
+        attacker: Keypair,
+        assetMint: Keypair;
+
+    let singleConfig: GenomeSingleConfig;
+    let tournamentConfig: TournamentConfig;
+
+    before(async () => {
+        const keys = await getKeyPairs();
+        ixBuilder = new IxBuilder();
+        organizer = keys.organizer;
+        sponsor = keys.sponsor;
+        captain1 = keys.captain1;
+        captain2 = keys.captain2;
+        verifier1 = keys.verifier1;
+        verifier2 = keys.verifier2;
+        verifier3 = keys.verifier3;
+        attacker = keys.attacker;
+        assetMint = keys.token;
+
+        singleConfig = await getSingleConfig();
+        tournamentId = singleConfig.tournamentNonce;
+        tournamentConfig = {
+            organizerFee: new BN(1000),
+            expirationTime: new BN((await getChainTime()) + 5),
+            sponsorPool: new BN(1000),
+            sponsor: sponsor.publicKey,
+            entryFee: new BN(150),
+            teamSize: 1,
+            minTeams: 2,
+            maxTeams: 10,
+            assetMint: assetMint.publicKey,
+            prizeDistribution: [10000],
+            allowlistRoot: null,
+            format: { freeForm: {} },
+            tiebreakers: [],
+            claimPeriod: new BN(15),
+            nativeSol: false,
+        };
+    });
+
+    it(`Start a tournament that never finishes [${MARKS.required}]`, async () => {
+        const createIx = await ixBuilder.createTournamentIx(
+            organizer.publicKey,
+            sponsor.publicKey,
+            assetMint.publicKey,
+            tournamentConfig,
+            {
+                name: "Genome Stale",
+                game: "dota2",
+                rulesUri: "",
+                rulesHash: Array(32).fill(0),
+            }
+        );
+        let txSig = await buildAndSendTx([createIx], [organizer]);
+        console.log("Create tournament tx:", txSig);
+
+        for (const captain of [captain1, captain2]) {
+            const registerIx = await ixBuilder.registerTournamentIx({
+                tournamentId,
+                participant: captain.publicKey,
+                captain: captain.publicKey,
+                teammates: [],
+                prepay: false,
+                inviteExpiration: new BN(0),
+                proofs: [],
+            });
+            txSig = await buildAndSendTx([registerIx], [captain]);
+            console.log("Register tournament tx:", txSig);
+        }
+
+        for (const verifier of [verifier1, verifier2]) {
+            const startIx = await ixBuilder.startTournamentIx(verifier.publicKey, tournamentId);
+            txSig = await buildAndSendTx([startIx], [verifier]);
+            console.log("Start tournament tx:", txSig);
+        }
+
+        // A single finish vote doesn't reach consensus
+        const finishIx = await ixBuilder.finishTournamentIx(verifier3.publicKey, tournamentId, [captain1.publicKey]);
+        txSig = await buildAndSendTx([finishIx], [verifier3]);
+        console.log("Finish tournament tx (verifier3):", txSig);
+
+        const tournament = await getTournament(tournamentId);
+        assert.ok(tournament.status.started, "Tournament should still be started");
+    });
+
+    it(`Settle verifier of a started tournament [${MARKS.negative}]`, async () => {
+        const settleIx = await ixBuilder.settleVerifierIx(attacker.publicKey, tournamentId, verifier3.publicKey);
+        try {
+            await buildAndSendTx([settleIx], [attacker]);
+            throw new Error("Expected error for premature settlement");
+        } catch (error) {
+            checkAnchorError(error, "Invalid tournament status");
+        }
+    });
+
+    it(`Expire a started tournament within the claim period [${MARKS.negative}]`, async () => {
+        await waitForChainTime(tournamentConfig.expirationTime.toNumber());
+
+        const expireIx = await ixBuilder.expireTournamentIx(attacker.publicKey, tournamentId);
+        try {
+            await buildAndSendTx([expireIx], [attacker]);
+            throw new Error("Expected error for premature expiry");
+        } catch (error) {
+            checkAnchorError(error, "Tournament has not expired yet");
+        }
+    });
+
+    it(`Expire a stale started tournament [${MARKS.required}]`, async () => {
+        await waitForChainTime(tournamentConfig.expirationTime.add(tournamentConfig.claimPeriod).toNumber());
+
+        const expireIx = await ixBuilder.expireTournamentIx(attacker.publicKey, tournamentId);
+        const txSig = await buildAndSendTx([expireIx], [attacker]);
+        console.log("Expire tournament tx:", txSig);
+
+        const tournament = await getTournament(tournamentId);
+        assert.ok(tournament.status.canceled, "Tournament should be canceled");
+    });
+
+    it(`Settle verifier of an expired tournament [${MARKS.required}]`, async () => {
+        const stakeBefore = await getVerifierStake(verifier3.publicKey);
+        const roleInfoBefore = await getRoleInfo(verifier3.publicKey);
+        const settleIx = await ixBuilder.settleVerifierIx(attacker.publicKey, tournamentId, verifier3.publicKey);
+        const txSig = await buildAndSendTx([settleIx], [attacker]);
+        console.log("Settle verifier tx:", txSig);
+
+        const stakeAfter = await getVerifierStake(verifier3.publicKey);
+        assert.equal(stakeAfter.unsettledVotes, stakeBefore.unsettledVotes - 1);
+        assert.equal(stakeAfter.amount.toNumber(), stakeBefore.amount.toNumber());
+        const roleInfoAfter = await getRoleInfo(verifier3.publicKey);
+        assert.equal(roleInfoAfter.claim.toNumber(), roleInfoBefore.claim.toNumber());
+
+        const finishMetaData = await getFinishInfo(tournamentId);
+        assert.equal(finishMetaData.settled.toNumber(), 0b1);
+    });
+});
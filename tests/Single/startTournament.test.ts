@@ -67,7 +67,10 @@ describe("Start Tournament", () => {
         tournamentId,
         participant: cap.publicKey,
         captain: cap.publicKey,
-        teammates: []
+        teammates: [],
+        prepay: false,
+        inviteExpiration: new BN(0),
+        proofs: [],
       };
       const regIx = await ixBuilder.registerTournamentIx(registerParams);
       await buildAndSendTx([regIx], [cap]);
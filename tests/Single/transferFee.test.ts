@@ -0,0 +1,144 @@
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { BN } from "@coral-xyz/anchor";
+import { TOKEN_2022_PROGRAM_ID } from "@solana/spl-token";
+import * as assert from "assert";
+import { describe, it } from "mocha";
+
+import { IxBuilder } from "../../common/ixBuilder";
+import { getKeyPairs, createTransferFeeMint, getChainTime, MARKS } from "../utils";
+import { getTournament, getTeam, buildAndSendTx, getAtaInfo, getGenomePda, getSingleConfig, TournamentConfig, TOURNAMENT } from "../../common/utils";
+
+describe("Transfer Fee Asset", () => {
+    const feeBasisPoints = 100; // 1%
+    let tournamentId: number;
+    let ixBuilder: IxBuilder;
+    let organizer: Keypair,
+        operator: Keypair,
+        sponsor: Keypair,
+        captain: Keypair,
+        participant: Keypair;
+
+    let assetMint: PublicKey,
+        tournamentPda: PublicKey;
+    let tournamentConfig: TournamentConfig;
+
+    // Amount left after a single transfer of a fee-bearing token
+    const net = (amount: number) => amount - (amount * feeBasisPoints) / 10000;
+
+    before(async () => {
+        const keys = await getKeyPairs();
+        ixBuilder = new IxBuilder();
+        organizer = keys.organizer;
+        operator = keys.operator;
+        sponsor = keys.sponsor;
+        captain = keys.captain2;
+        participant = keys.participant2;
+
+        assetMint = await createTransferFeeMint(feeBasisPoints);
+        const approveIx = await ixBuilder.approveTokenIx(operator.publicKey, assetMint, new BN(1000), new BN(100));
+        const txSig = await buildAndSendTx([approveIx], [operator]);
+        console.log("Approve token tx:", txSig);
+
+        tournamentId = (await getSingleConfig()).tournamentNonce;
+        const idBuffer = Buffer.alloc(4);
+        idBuffer.writeUInt32LE(tournamentId, 0);
+        tournamentPda = await getGenomePda([TOURNAMENT, idBuffer]);
+        tournamentConfig = {
+            organizerFee: new BN(1000),
+            expirationTime: new BN((await getChainTime()) + 60 * 60),
+            sponsorPool: new BN(100000),
+            sponsor: sponsor.publicKey,
+            entryFee: new BN(10000),
+            teamSize: 2,
+            minTeams: 2,
+            maxTeams: 10,
+            assetMint,
+            prizeDistribution: [10000],
+            allowlistRoot: null,
+            format: { freeForm: {} },
+            tiebreakers: [],
+            claimPeriod: null,
+            nativeSol: false,
+        };
+    });
+
+    it(`Create a tournament with a fee-bearing asset [${MARKS.required}]`, async () => {
+        const createIx = await ixBuilder.createTournamentIx(
+            organizer.publicKey,
+            sponsor.publicKey,
+            assetMint,
+            tournamentConfig,
+            {
+                name: "Genome Fee Cup",
+                game: "dota2",
+                rulesUri: "",
+                rulesHash: Array(32).fill(0),
+            }
+        );
+        const txSig = await buildAndSendTx([createIx], [organizer]);
+        console.log("Create tournament tx:", txSig);
+
+        const tournament = await getTournament(tournamentId);
+        const sponsorPool = net(tournamentConfig.sponsorPool.toNumber());
+        assert.equal(tournament.sponsorPool.toNumber(), sponsorPool);
+        assert.equal(tournament.totalDeposited.toNumber(), sponsorPool);
+
+        const rewardPoolAta = await getAtaInfo(assetMint, tournamentPda, TOKEN_2022_PROGRAM_ID);
+        assert.equal(Number(rewardPoolAta.amount), sponsorPool);
+    });
+
+    it(`Register with a fee-bearing asset [${MARKS.required}]`, async () => {
+        for (const member of [captain, participant]) {
+            const registerIx = await ixBuilder.registerTournamentIx({
+                tournamentId,
+                participant: member.publicKey,
+                captain: captain.publicKey,
+                teammates: [],
+                prepay: false,
+                inviteExpiration: new BN(0),
+                proofs: [],
+            });
+            const txSig = await buildAndSendTx([registerIx], [member]);
+            console.log("Register tournament tx:", txSig);
+        }
+
+        const entryFee = net(tournamentConfig.entryFee.toNumber());
+        const team = await getTeam(tournamentId, captain.publicKey);
+        for (const p of team.participants) {
+            assert.equal(p.deposit.toNumber(), entryFee);
+        }
+
+        const tournament = await getTournament(tournamentId);
+        assert.equal(tournament.entryFees.toNumber(), entryFee * 2);
+        const rewardPoolAta = await getAtaInfo(assetMint, tournamentPda, TOKEN_2022_PROGRAM_ID);
+        assert.equal(Number(rewardPoolAta.amount), tournament.totalDeposited.toNumber());
+    });
+
+    it(`Refund everyone with a fee-bearing asset [${MARKS.required}]`, async () => {
+        const sponsorAtaBefore = await getAtaInfo(assetMint, sponsor.publicKey, TOKEN_2022_PROGRAM_ID);
+        const cancelIx = await ixBuilder.organizerCancelIx(organizer.publicKey, tournamentId);
+        let txSig = await buildAndSendTx([cancelIx], [organizer]);
+        console.log("Organizer cancel tx:", txSig);
+
+        const sponsorAtaAfter = await getAtaInfo(assetMint, sponsor.publicKey, TOKEN_2022_PROGRAM_ID);
+        const sponsorPool = net(tournamentConfig.sponsorPool.toNumber());
+        assert.equal(Number(sponsorAtaAfter.amount - sponsorAtaBefore.amount), net(sponsorPool));
+
+        const entryFee = net(tournamentConfig.entryFee.toNumber());
+        for (const member of [captain, participant]) {
+            const memberAtaBefore = await getAtaInfo(assetMint, member.publicKey, TOKEN_2022_PROGRAM_ID);
+            const claimRefundIx = await ixBuilder.claimRefundIx(member.publicKey, tournamentId, captain.publicKey);
+            txSig = await buildAndSendTx([claimRefundIx], [member]);
+            console.log("Claim refund tx:", txSig);
+
+            const memberAtaAfter = await getAtaInfo(assetMint, member.publicKey, TOKEN_2022_PROGRAM_ID);
+            assert.equal(Number(memberAtaAfter.amount - memberAtaBefore.amount), net(entryFee));
+        }
+
+        // The last claimer is paid in full and nothing is left behind in the pool
+        const tournament = await getTournament(tournamentId);
+        assert.equal(tournament.refundsPaid.toNumber(), tournament.totalDeposited.toNumber());
+        const rewardPoolAta = await getAtaInfo(assetMint, tournamentPda, TOKEN_2022_PROGRAM_ID);
+        assert.equal(Number(rewardPoolAta.amount), 0);
+    });
+});
@@ -1,5 +1,18 @@
-import { approveChecked, ASSOCIATED_TOKEN_PROGRAM_ID, createAssociatedTokenAccount, createMint, mintTo, TOKEN_PROGRAM_ID } from "@solana/spl-token";
-import { Keypair, PublicKey } from "@solana/web3.js";
+import {
+  approveChecked,
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  createAssociatedTokenAccount,
+  createInitializeMintInstruction,
+  createInitializeTransferFeeConfigInstruction,
+  createMint,
+  ExtensionType,
+  getAssociatedTokenAddress,
+  getMintLen,
+  mintTo,
+  TOKEN_2022_PROGRAM_ID,
+  TOKEN_PROGRAM_ID,
+} from "@solana/spl-token";
+import { Keypair, PublicKey, sendAndConfirmTransaction, SystemProgram, Transaction } from "@solana/web3.js";
 import { getKeypairFromFile } from "@solana-developers/helpers";
 import { AnchorError } from "@coral-xyz/anchor";
 import { assert } from "chai";
@@ -201,6 +214,75 @@ export async function createTournamentMint(): Promise<{
   return { assetMint, sponsorAta };
 }
 
+// Token-2022 mint with the transfer fee extension, funded for the sponsor and the second team
+export async function createTransferFeeMint(feeBasisPoints: number): Promise<PublicKey> {
+  const { admin, sponsor, organizer, captain2, participant2 } = await getKeyPairs();
+  const connection = getProvider().connection;
+  const mint = Keypair.generate();
+
+  const mintLen = getMintLen([ExtensionType.TransferFeeConfig]);
+  const lamports = await connection.getMinimumBalanceForRentExemption(mintLen);
+  const tx = new Transaction().add(
+    SystemProgram.createAccount({
+      fromPubkey: admin.publicKey,
+      newAccountPubkey: mint.publicKey,
+      space: mintLen,
+      lamports,
+      programId: TOKEN_2022_PROGRAM_ID,
+    }),
+    createInitializeTransferFeeConfigInstruction(
+      mint.publicKey,
+      admin.publicKey,
+      admin.publicKey,
+      feeBasisPoints,
+      BigInt(1000000000000000),
+      TOKEN_2022_PROGRAM_ID
+    ),
+    createInitializeMintInstruction(mint.publicKey, 9, admin.publicKey, null, TOKEN_2022_PROGRAM_ID)
+  );
+  await sendAndConfirmTransaction(connection, tx, [admin, mint]);
+
+  for (const account of [sponsor, captain2, participant2]) {
+    const ata = await createAssociatedTokenAccount(
+      connection,
+      account,
+      mint.publicKey,
+      account.publicKey,
+      undefined,
+      TOKEN_2022_PROGRAM_ID,
+      ASSOCIATED_TOKEN_PROGRAM_ID
+    );
+    await mintTo(
+      connection,
+      account,
+      mint.publicKey,
+      ata,
+      admin,
+      1000000000000,
+      [],
+      {},
+      TOKEN_2022_PROGRAM_ID
+    );
+  }
+
+  const sponsorAta = await getAssociatedTokenAddress(mint.publicKey, sponsor.publicKey, true, TOKEN_2022_PROGRAM_ID);
+  await approveChecked(
+    connection,
+    admin,
+    mint.publicKey,
+    sponsorAta,
+    organizer.publicKey,
+    sponsor,
+    1e8,
+    9,
+    [],
+    {},
+    TOKEN_2022_PROGRAM_ID
+  );
+
+  return mint.publicKey;
+}
+
 export async function delegateAccount(sponsorAta: PublicKey): Promise<String> {
   let provider = getProvider();
   let { admin, sponsor, organizer, token } = await getKeyPairs();
@@ -222,3 +304,14 @@ export async function delegateAccount(sponsorAta: PublicKey): Promise<String> {
 export function sleep(ms: number) {
   return new Promise((resolve) => setTimeout(resolve, ms));
 }
+
+export async function getChainTime(): Promise<number> {
+  const connection = getProvider().connection;
+  return await connection.getBlockTime(await connection.getSlot());
+}
+
+export async function waitForChainTime(timestamp: number): Promise<void> {
+  while ((await getChainTime()) <= timestamp) {
+    await sleep(1000);
+  }
+}